@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::DateTime;
+use serde::Serialize;
+
+use crate::{commit::CommitInfo, config::AuthorWeighting};
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorStats {
+    pub name: String,
+    pub email: String,
+    /// Weighted by `--author-weighting`, fractional when co-authored commits are split.
+    pub commits: f64,
+    pub insertions: f64,
+    pub deletions: f64,
+    pub co_authored: u32,
+    pub files_touched: usize,
+    pub active_days: usize,
+    pub first_commit: i64,
+    pub last_commit: i64,
+    /// Share of the commits of the bucket.
+    pub freq: f64,
+    pub top_types: Vec<TopEntry>,
+    pub top_scopes: Vec<TopEntry>,
+    /// Files with the most changed lines, `count` is the number of changed lines.
+    pub top_files: Vec<TopEntry>,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct TopEntry {
+    pub name: String,
+    pub count: u64,
+}
+
+const TOP_ENTRIES: usize = 3;
+
+#[derive(Default)]
+struct AuthorAcc {
+    stats: AuthorStats,
+    types: HashMap<String, u64>,
+    scopes: HashMap<String, u64>,
+    files: HashMap<String, u64>,
+    days: HashSet<String>,
+}
+
+impl AuthorStats {
+    /// Credits every commit to its participants, sorted by the weighted commit count.
+    pub fn collect(commits: &[CommitInfo], weighting: &AuthorWeighting) -> Vec<AuthorStats> {
+        let mut accs: HashMap<String, AuthorAcc> = HashMap::new();
+
+        for commit in commits.iter() {
+            let participants = match weighting {
+                AuthorWeighting::Primary => vec![&commit.author],
+                _ => commit.participants(),
+            };
+            let share = match weighting {
+                AuthorWeighting::Split => 1.0 / participants.len() as f64,
+                _ => 1.0,
+            };
+            let (insertions, deletions) = commit
+                .stats
+                .as_ref()
+                .map_or((0, 0), |stats| (stats.insertions, stats.deletions));
+            let day = DateTime::from_timestamp(commit.time, 0)
+                .map(|time| time.format("%Y-%m-%d").to_string());
+
+            for author in participants.iter() {
+                let acc = accs.entry(author.key()).or_insert_with(|| AuthorAcc {
+                    stats: AuthorStats {
+                        name: author.name.clone(),
+                        email: author.email.clone(),
+                        first_commit: commit.time,
+                        last_commit: commit.time,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                });
+
+                let stats = &mut acc.stats;
+                stats.commits += share;
+                stats.insertions += insertions as f64 * share;
+                stats.deletions += deletions as f64 * share;
+                if participants.len() > 1 {
+                    stats.co_authored += 1;
+                }
+                stats.first_commit = stats.first_commit.min(commit.time);
+                stats.last_commit = stats.last_commit.max(commit.time);
+
+                if !commit.type_.is_empty() {
+                    *acc.types.entry(commit.type_.clone()).or_default() += 1;
+                }
+                if !commit.scope.is_empty() {
+                    *acc.scopes.entry(commit.scope.clone()).or_default() += 1;
+                }
+                if let Some(stats) = &commit.stats {
+                    for file_stat_info in stats.file_stat_infos.iter() {
+                        *acc.files.entry(file_stat_info.path.clone()).or_default() +=
+                            file_stat_info.total_changes.unsigned_abs();
+                    }
+                }
+                acc.days.extend(day.clone());
+            }
+        }
+
+        let mut authors = accs
+            .into_values()
+            .map(|acc| AuthorStats {
+                files_touched: acc.files.len(),
+                active_days: acc.days.len(),
+                freq: acc.stats.commits / commits.len() as f64,
+                top_types: top_entries(acc.types),
+                top_scopes: top_entries(acc.scopes),
+                top_files: top_entries(acc.files),
+                ..acc.stats
+            })
+            .collect::<Vec<AuthorStats>>();
+        authors.sort_by(|a, b| {
+            b.commits
+                .total_cmp(&a.commits)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        authors
+    }
+}
+
+fn top_entries(counts: HashMap<String, u64>) -> Vec<TopEntry> {
+    let mut entries = counts
+        .into_iter()
+        .map(|(name, count)| TopEntry { name, count })
+        .collect::<Vec<TopEntry>>();
+    entries.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+    entries.truncate(TOP_ENTRIES);
+
+    entries
+}
+
+impl TopEntry {
+    /// Formats entries as `feat 3, fix 1`.
+    pub fn join(entries: &[TopEntry]) -> String {
+        entries
+            .iter()
+            .map(|entry| format!("{} {}", entry.name, entry.count))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
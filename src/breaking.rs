@@ -0,0 +1,56 @@
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use serde::Serialize;
+
+use crate::commit::CommitInfo;
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakingInfo {
+    pub id: String,
+    pub scope: String,
+    pub description: String,
+    pub time: i64,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakingSummary {
+    pub total: u32,
+    pub changes: Vec<BreakingInfo>,
+    pub scopes: BTreeMap<String, u32>,
+    /// Breaking changes per month, keyed by `YYYY-MM`.
+    pub months: BTreeMap<String, u32>,
+}
+
+impl BreakingSummary {
+    pub fn collect(commits: &[CommitInfo]) -> BreakingSummary {
+        let mut summary = BreakingSummary::default();
+
+        for commit in commits.iter().filter(|commit| commit.breaking) {
+            summary.total += 1;
+            summary.changes.push(BreakingInfo {
+                id: commit.id.clone(),
+                scope: commit.scope.clone(),
+                description: commit
+                    .breaking_description
+                    .clone()
+                    .unwrap_or(commit.summary.clone()),
+                time: commit.time,
+            });
+
+            if !commit.scope.is_empty() {
+                *summary.scopes.entry(commit.scope.clone()).or_default() += 1;
+            }
+            if let Some(time) = DateTime::from_timestamp(commit.time, 0) {
+                *summary
+                    .months
+                    .entry(time.format("%Y-%m").to_string())
+                    .or_default() += 1;
+            }
+        }
+
+        summary
+    }
+}
@@ -13,10 +13,7 @@ pub struct Cache {
 
 impl Cache {
     pub fn new(file_path: &str) -> Self {
-        let cache = match Self::read_cache(file_path) {
-            Ok(cache) => cache,
-            Err(_) => HashMap::new(),
-        };
+        let cache = Self::read_cache(file_path).unwrap_or_default();
 
         Cache {
             cache,
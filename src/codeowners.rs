@@ -0,0 +1,213 @@
+use std::{fs, path::Path};
+
+use crate::{
+    config::OwnershipConfig,
+    customerror::{Error, Result},
+};
+
+/// Places searched for a CODEOWNERS file, in the order GitHub does.
+const LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Groups and rules of a CODEOWNERS file.
+#[derive(Debug, Default)]
+pub struct CodeOwners {
+    /// One group per owner, `@team`, `@user` or email, with no patterns of its own.
+    pub groups: Vec<OwnershipConfig>,
+    /// Rules in the order of the file.
+    pub rules: Vec<CodeOwnersRule>,
+}
+
+/// A line of CODEOWNERS, the last matching rule of the file owns a path.
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwnersRule {
+    /// Regex over repository relative paths.
+    pub pattern: String,
+    /// Owners of the matched paths, none for a rule that leaves them unowned.
+    pub owners: Vec<String>,
+}
+
+/// Reads the CODEOWNERS file of the work tree, none when there is no such file.
+pub fn load(root: &Path) -> Result<Option<CodeOwners>> {
+    let Some(path) = LOCATIONS
+        .iter()
+        .map(|location| root.join(location))
+        .find(|path| path.is_file())
+    else {
+        return Ok(None);
+    };
+
+    parse(&fs::read_to_string(path)?).map(Some)
+}
+
+pub fn parse(content: &str) -> Result<CodeOwners> {
+    let mut codeowners = CodeOwners::default();
+
+    for (indx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut tokens = line.split_whitespace();
+        let Some(pattern) = tokens.next() else {
+            continue;
+        };
+        let owners = tokens
+            .take_while(|token| !token.starts_with('#'))
+            .map(|owner| owner.to_string())
+            .collect::<Vec<String>>();
+
+        for owner in owners.iter() {
+            if !owner.contains('@') {
+                return Err(Error::ParseError(format!(
+                    "invalid owner `{}` on line {} of CODEOWNERS, expected @team, @user or email",
+                    owner,
+                    indx + 1
+                )));
+            }
+            if !codeowners.groups.iter().any(|group| &group.name == owner) {
+                codeowners.groups.push(OwnershipConfig {
+                    name: owner.to_string(),
+                    patterns: vec![],
+                    authors: member_of(owner).into_iter().collect(),
+                });
+            }
+        }
+
+        codeowners.rules.push(CodeOwnersRule {
+            pattern: glob_to_regex(pattern.strip_prefix('\\').unwrap_or(pattern)),
+            owners,
+        });
+    }
+
+    Ok(codeowners)
+}
+
+/// Identity a commit author is matched with, teams list no members.
+fn member_of(owner: &str) -> Option<String> {
+    match owner.strip_prefix('@') {
+        Some(handle) if handle.contains('/') => None,
+        Some(handle) => Some(handle.to_string()),
+        None => Some(owner.to_string()),
+    }
+}
+
+/// Converts a gitignore style pattern to a regex over repository relative paths.
+pub fn glob_to_regex(pattern: &str) -> String {
+    let directory = pattern.ends_with('/');
+    let glob = pattern.trim_start_matches('/').trim_end_matches('/');
+    // a slash anywhere but at the end anchors the pattern to the root
+    let anchored = pattern.trim_end_matches('/').contains('/');
+
+    let mut regex = String::from("^");
+    if !anchored {
+        regex.push_str("(?:.*/)?");
+    }
+
+    let chars = glob.chars().collect::<Vec<char>>();
+    let mut indx = 0;
+    while indx < chars.len() {
+        match chars[indx] {
+            '*' if chars.get(indx + 1) == Some(&'*') => {
+                if chars.get(indx + 2) == Some(&'/') {
+                    regex.push_str("(?:.*/)?");
+                    indx += 3;
+                } else {
+                    regex.push_str(".*");
+                    indx += 2;
+                }
+                continue;
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+        indx += 1;
+    }
+
+    if directory {
+        regex.push_str("/.*$");
+    } else if glob.ends_with("/*") {
+        // `docs/*` owns the files of docs but not the ones of its subdirectories
+        regex.push('$');
+    } else {
+        regex.push_str("(?:/.*)?$");
+    }
+
+    regex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ownerships::PathOwners;
+
+    #[test]
+    fn can_convert_globs() {
+        let matches = |glob: &str, path: &str| {
+            regex::Regex::new(&glob_to_regex(glob))
+                .unwrap()
+                .is_match(path)
+        };
+
+        assert!(matches("*.js", "web/app.js"));
+        assert!(!matches("*.js", "web/app.jsx"));
+        assert!(matches("/build/logs/", "build/logs/a/b.log"));
+        assert!(!matches("/build/logs/", "src/build/logs/a.log"));
+        assert!(matches("apps/", "src/apps/main.rs"));
+        assert!(matches("docs/*", "docs/intro.md"));
+        assert!(!matches("docs/*", "docs/guide/intro.md"));
+        assert!(matches("**/logs", "a/b/logs/today.log"));
+        assert!(matches("src/**/mod.rs", "src/mod.rs"));
+        assert!(matches("src/**/mod.rs", "src/a/b/mod.rs"));
+        assert!(matches("README", "README"));
+        assert!(!matches("README", "README.md"));
+    }
+
+    #[test]
+    fn can_parse_codeowners() {
+        let codeowners = parse(
+            "# owners\n\
+             *       @org/core\n\
+             *.js    @org/web alice@example.com # frontend\n\
+             /docs/  @bob\n\
+             /docs/api/ @org/core\n\
+             /vendor/\n",
+        )
+        .unwrap();
+        let groups = &codeowners.groups;
+
+        let names = groups.iter().map(|g| g.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["@org/core", "@org/web", "alice@example.com", "@bob"]
+        );
+        assert!(groups[0].authors.is_empty());
+        assert_eq!(groups[2].authors, vec!["alice@example.com"]);
+        assert_eq!(groups[3].authors, vec!["bob"]);
+
+        assert_eq!(codeowners.rules.len(), 5);
+        assert!(codeowners.rules[4].owners.is_empty());
+
+        let path_owners = PathOwners::new(groups, &codeowners.rules).unwrap();
+        let owners = |path: &str| {
+            path_owners
+                .owners(path)
+                .into_iter()
+                .map(|indx| groups[indx].name.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(owners("src/main.rs"), vec!["@org/core"]);
+        assert_eq!(owners("web/app.js"), vec!["@org/web", "alice@example.com"]);
+        assert_eq!(owners("docs/intro.md"), vec!["@bob"]);
+        assert_eq!(owners("docs/api/v1.js"), vec!["@org/core"]);
+        assert!(owners("vendor/lib.js").is_empty());
+
+        // the last matching rule decides even when its owner is not reported
+        let without_core = PathOwners::new(&groups[1..], &codeowners.rules).unwrap();
+        assert!(without_core.owners("docs/api/v1.js").is_empty());
+        assert_eq!(without_core.owners("docs/intro.md"), vec![2]);
+
+        assert!(parse("*.rs rustaceans").is_err());
+    }
+}
@@ -1,25 +1,33 @@
+use crate::authors::AuthorStats;
+use crate::breaking::BreakingSummary;
 use crate::cache::Cache;
 use crate::config::Config;
-use crate::convention::ConventionBuilder;
+use crate::convention::{ConventionBuilder, ParsedCommitInfo};
 use crate::customerror::Result;
-use crate::repo::Repo;
+use crate::identity::IdentityResolver;
+use crate::message::{MessageParser, Trailer, TrailerFilter};
+use crate::repo::{Repo, RevisionSelection};
+use crate::revert::{link_reverts, RevertDetector, RevertRef, RevertSummary};
 use crate::tracker::{Tracker, TrackerOpts};
 use crate::utils::parse_date;
 use colored::Color;
-use git2::{Commit, DiffOptions, DiffStatsFormat};
+use git2::{Commit, DiffOptions, DiffStatsFormat, Oid};
 use log::info;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
 use std::vec;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Author {
     pub name: String,
     pub email: String,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Stats {
     pub cm_id: String,
     pub file_stat_infos: Vec<FileStatInfo>,
@@ -36,17 +44,24 @@ pub struct FileStatInfo {
     pub total_changes: i64,
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Clone, Debug, Default)]
 pub struct CommitInfo {
+    pub id: String,
     pub author: Author,
     pub summary: String,
+    pub body: String,
+    pub trailers: Vec<Trailer>,
+    pub co_authors: Vec<Author>,
     pub type_: String,
     pub scope: String,
     pub stats: Option<Stats>,
     pub time: i64,
+    pub revert_of: Option<RevertRef>,
+    pub breaking: bool,
+    pub breaking_description: Option<String>,
 }
 
-#[derive(Serialize, Clone)]
+#[derive(Serialize, Clone, Debug)]
 pub struct CommitBucket {
     pub commits: Vec<CommitInfo>,
     pub info: BucketInfo,
@@ -66,12 +81,102 @@ pub struct Freq {
     pub info: HashMap<String, FreqInfo>,
 }
 
-#[derive(Serialize, Clone)]
+#[derive(Serialize, Clone, Debug)]
 pub struct BucketInfo {
     pub types: Freq,
     pub scopes: Freq,
+    pub trailers: Freq,
     pub file_summs: FileSumms,
     pub total: usize,
+    pub reverts: RevertSummary,
+    pub breaking: BreakingSummary,
+    pub authors: Vec<AuthorStats>,
+}
+
+impl Author {
+    /// Parses `Name <email>` as written in `Co-authored-by` trailers.
+    pub fn parse(value: &str) -> Author {
+        match value.rsplit_once('<') {
+            Some((name, email)) => Author {
+                name: name.trim().to_string(),
+                email: email.trim_end_matches('>').trim().to_string(),
+            },
+            None => Author {
+                name: value.trim().to_string(),
+                email: String::new(),
+            },
+        }
+    }
+
+    /// Identity used to aggregate authors, the email when there is one.
+    pub fn key(&self) -> String {
+        match self.email.is_empty() {
+            true => self.name.to_lowercase(),
+            false => self.email.to_lowercase(),
+        }
+    }
+}
+
+impl CommitInfo {
+    /// The message rebuilt from the summary, the body and the trailers.
+    pub fn message(&self) -> String {
+        let trailers = self
+            .trailers
+            .iter()
+            .map(|trailer| format!("{}: {}", trailer.key, trailer.value))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        [self.summary.as_str(), self.body.as_str(), trailers.as_str()]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<&str>>()
+            .join("\n\n")
+    }
+
+    /// The author followed by the `Co-authored-by` trailers.
+    pub fn participants(&self) -> Vec<&Author> {
+        let mut participants = vec![&self.author];
+        participants.extend(self.co_authors.iter());
+        participants
+    }
+
+    /// Copy keeping the changed files that match, with the totals of the stats recomputed.
+    pub fn with_files<F: Fn(&FileStatInfo) -> bool>(&self, keep: F) -> CommitInfo {
+        let mut commit = self.clone();
+        if let Some(stats) = commit.stats.as_mut() {
+            stats
+                .file_stat_infos
+                .retain(|file_stat_info| keep(file_stat_info));
+            stats.changed_files_count = stats.file_stat_infos.len();
+            stats.insertions = stats.file_stat_infos.iter().map(|f| f.inserted).sum();
+            stats.deletions = stats.file_stat_infos.iter().map(|f| f.deleted).sum();
+            stats.total_changes = stats.insertions + stats.deletions;
+        }
+        commit
+    }
+}
+
+impl Freq {
+    pub fn from_counts(counts: HashMap<String, u32>) -> Freq {
+        let total = counts.values().sum::<u32>();
+
+        Freq {
+            total,
+            info: counts
+                .into_iter()
+                .map(|(key, count)| {
+                    (
+                        key,
+                        FreqInfo {
+                            count,
+                            freq: count as f64 / total as f64,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
 }
 
 impl CommitBucket {
@@ -80,40 +185,38 @@ impl CommitBucket {
         example_commit_message: &str,
         config: &Config,
     ) -> Result<CommitBucket> {
-        let mut perf_tracker = Tracker::new(&config, Color::Cyan, None);
-        let mut cache = Cache::new(&format!(
-            "{}/{}",
-            &config.path.clone().unwrap_or("./".to_string()),
-            &config.cache_path
-        ));
+        let mut perf_tracker = Tracker::new(config, Color::Cyan, None);
+        // an absolute cache path is kept as is
+        let mut cache = Cache::new(
+            &Path::new(config.path.as_deref().unwrap_or("./"))
+                .join(&config.cache_path)
+                .to_string_lossy(),
+        );
 
         perf_tracker.start("commit :: get commits from repo");
-        let g_commits = repo.get_commits()?;
+        let g_commits = repo.get_commits(&RevisionSelection::from(config), &config.commit_sort)?;
         perf_tracker.stop();
 
         perf_tracker.start("commit :: filter commits by date");
-        let g_commits =
-            g_commits
-                .iter()
-                .filter(|commit| {
-                    let is_after_start = config.start_date.as_ref().map_or(true, |start_date| {
-                        parse_date(&start_date, &config.date_format, &config.date_format_type)
-                            .map_or(true, |parsed_start_date| {
-                                return commit.time().seconds().cmp(&parsed_start_date).is_ge();
-                            })
-                    });
-
-                    let is_before_end =
-                        config.end_date.as_ref().map_or(true, |end_date| {
-                            parse_date(&end_date, &config.date_format, &config.date_format_type)
-                                .map_or(true, |parsed_end_date| {
-                                    return commit.time().seconds().cmp(&parsed_end_date).is_le();
-                                })
-                        });
-
-                    is_after_start && is_before_end
-                })
-                .collect::<Vec<&Commit>>();
+        let g_commits = g_commits
+            .iter()
+            .filter(|commit| {
+                let is_after_start = config.start_date.as_ref().is_none_or(|start_date| {
+                    parse_date(start_date, &config.date_format, &config.date_format_type)
+                        .is_none_or(|parsed_start_date| {
+                            commit.time().seconds().cmp(&parsed_start_date).is_ge()
+                        })
+                });
+
+                let is_before_end = config.end_date.as_ref().is_none_or(|end_date| {
+                    parse_date(end_date, &config.date_format, &config.date_format_type).is_none_or(
+                        |parsed_end_date| commit.time().seconds().cmp(&parsed_end_date).is_le(),
+                    )
+                });
+
+                is_after_start && is_before_end
+            })
+            .collect::<Vec<&Commit>>();
 
         perf_tracker.stop();
 
@@ -123,60 +226,118 @@ impl CommitBucket {
         let convention_builder = ConventionBuilder::build(example_commit_message);
         perf_tracker.stop();
 
+        let revert_detector = RevertDetector::build(&config.revert_message_pattern);
+        let message_parser = MessageParser::build();
+        let identity_resolver = IdentityResolver::new(
+            repo.mailmap().ok(),
+            config.author_aliases.as_deref().unwrap_or_default(),
+        );
+        let trailer_filters = config
+            .filter_trailers
+            .iter()
+            .flatten()
+            .map(|filter| TrailerFilter::parse(filter))
+            .collect::<Result<Vec<TrailerFilter>>>()?;
+
+        perf_tracker.start("commit :: compute diff stats");
+        let file_stats = Self::collect_file_stats(repo, &mut cache, config, &g_commits)?;
+        perf_tracker.stop();
+
         perf_tracker.start("commit :: parse commit wrt convention builder");
-        for g_commit in g_commits {
-            let parsed_message_info = convention_builder
-                .construct_info(g_commit.summary().unwrap_or("").to_string())
-                .unwrap_or_default();
+        for (g_commit, file_stat_infos) in g_commits.into_iter().zip(file_stats) {
+            let summary = g_commit.summary().unwrap_or("");
+            let raw_body = g_commit.body().unwrap_or("");
+            let revert_of = revert_detector.detect(summary, raw_body);
+            let (body, trailers) = message_parser.parse(raw_body);
+            let author = identity_resolver.resolve_signature(&g_commit.author());
+            let mut co_authors: Vec<Author> = vec![];
+            for trailer in trailers.iter().filter(|t| t.key == "Co-authored-by") {
+                let co_author = identity_resolver.resolve(Author::parse(&trailer.value));
+                if co_author.key() != author.key()
+                    && co_authors.iter().all(|c| c.key() != co_author.key())
+                {
+                    co_authors.push(co_author);
+                }
+            }
+
+            // a revert is parsed from the reverted summary, e.g., `Revert "feat(x): y"`
+            let parsed_message_info = match revert_of.as_ref().and_then(|r| r.summary.as_ref()) {
+                Some(reverted_summary) => convention_builder
+                    .construct_info(reverted_summary, "")
+                    .map(|info| ParsedCommitInfo {
+                        type_: "revert".to_string(),
+                        breaking: false,
+                        breaking_description: None,
+                        ..info
+                    })
+                    .unwrap_or(ParsedCommitInfo {
+                        type_: "revert".to_string(),
+                        ..Default::default()
+                    }),
+                None => convention_builder
+                    .construct_info(summary, raw_body)
+                    .unwrap_or_default(),
+            };
 
             let commit_info: CommitInfo = CommitInfo {
-                author: Author {
-                    name: g_commit.author().name().unwrap_or("").to_string(),
-                    email: g_commit.author().email().unwrap_or("").to_string(),
-                },
-                summary: g_commit.summary().unwrap_or("").to_string(),
+                id: g_commit.id().to_string(),
+                author,
+                summary: summary.to_string(),
+                body,
+                trailers,
+                co_authors,
                 type_: parsed_message_info.type_,
                 scope: parsed_message_info.optional_scope.unwrap_or("".to_string()),
-                stats: Self::get_stats(&repo, &mut cache, &config, &g_commit),
+                stats: Self::get_stats(config, g_commit, file_stat_infos),
                 time: g_commit.time().seconds(),
+                revert_of,
+                breaking: parsed_message_info.breaking,
+                breaking_description: parsed_message_info.breaking_description,
             };
 
             commits.push(commit_info);
         }
+        link_reverts(&mut commits);
         perf_tracker.stop();
 
         perf_tracker.start("commit :: filter commits by config");
         let commits: Vec<CommitInfo> = commits
             .into_iter()
             .filter(|info| {
-                config
-                    .filter_authors
-                    .as_ref()
-                    .map_or(true, |author| author.contains(&info.author.name))
+                config.filter_authors.as_ref().is_none_or(|authors| {
+                    info.participants()
+                        .iter()
+                        .any(|author| authors.contains(&author.name))
+                })
             })
             .filter(|info| {
                 config
                     .filter_scopes
                     .as_ref()
-                    .map_or(true, |scope| scope.contains(&info.scope))
+                    .is_none_or(|scope| scope.contains(&info.scope))
             })
             .filter(|info| {
                 config
                     .filter_types
                     .as_ref()
-                    .map_or(true, |type_| type_.contains(&info.type_))
+                    .is_none_or(|type_| type_.contains(&info.type_))
+            })
+            .filter(|info| {
+                trailer_filters
+                    .iter()
+                    .all(|filter| filter.matches(&info.trailers))
             })
             .filter(|info| {
                 config
                     .exclude_filename_patterns
                     .as_ref()
-                    .map_or(true, |filename_patterns| {
+                    .is_none_or(|filename_patterns| {
                         for pattern in filename_patterns {
-                            let pattern = format!(r"{}", pattern);
+                            let pattern = pattern.to_string();
                             let regex = Regex::new(&pattern).unwrap();
                             match &info.stats {
                                 Some(stats) => {
-                                    for file_stat_info in &stats.file_stat_infos {
+                                    if let Some(file_stat_info) = stats.file_stat_infos.first() {
                                         if regex.is_match(&file_stat_info.path) {
                                             return false;
                                         }
@@ -193,10 +354,10 @@ impl CommitBucket {
                 config
                     .filter_filename_pattern
                     .as_ref()
-                    .map_or(true, |filename_pattern| {
-                        let regex = Regex::new(&format!(r"{}", filename_pattern)).unwrap();
+                    .is_none_or(|filename_pattern| {
+                        let regex = Regex::new(&filename_pattern.to_string()).unwrap();
                         if let Some(stats) = &info.stats {
-                            for file_stat_info in &stats.file_stat_infos {
+                            if let Some(file_stat_info) = stats.file_stat_infos.first() {
                                 if !regex.is_match(file_stat_info.path.as_str()) {
                                     return false;
                                 }
@@ -210,7 +371,7 @@ impl CommitBucket {
         perf_tracker.stop();
 
         perf_tracker.start("commit :: collect bucket info");
-        let bucket_info = Self::collect_bucket_info(&commits);
+        let bucket_info = Self::collect_bucket_info(&commits, config);
         perf_tracker.stop();
 
         Ok(CommitBucket {
@@ -219,91 +380,154 @@ impl CommitBucket {
         })
     }
 
-    fn get_stats(
+    /// File stats of every commit, read from the cache or computed by `config.jobs` workers.
+    fn collect_file_stats(
         repo: &Repo,
         cache: &mut Cache,
         config: &Config,
-        commit: &Commit,
-    ) -> Option<Stats> {
-        let mut file_stat_infos: Option<Vec<FileStatInfo>> = None;
+        commits: &[&Commit],
+    ) -> Result<Vec<Option<Vec<FileStatInfo>>>> {
+        let mut file_stats = commits
+            .iter()
+            .map(|commit| {
+                let stats = cache.get(commit.id().to_string())?;
+                info!("get commit {} stats from cache", commit.id());
+                serde_json::from_str(stats).ok()
+            })
+            .collect::<Vec<Option<Vec<FileStatInfo>>>>();
 
-        let mut perf_tracker = Tracker::new(
-            &config,
-            Color::Cyan,
-            Some(TrackerOpts {
-                write_once: Some(true),
-            }),
-        );
-        perf_tracker.start("commit :: get_stats :: get commit stat from cache");
-        if let Some(stats) = cache.get(commit.id().to_string()) {
-            info!("get commit {} stats from cache", commit.id());
-            if let Ok(stats) = serde_json::from_str(stats) {
-                file_stat_infos = Some(stats);
+        let uncached = (0..commits.len())
+            .filter(|indx| file_stats[*indx].is_none())
+            .collect::<Vec<usize>>();
+        let oids = uncached
+            .iter()
+            .map(|indx| commits[*indx].id())
+            .collect::<Vec<Oid>>();
+        let computed = Self::diff_file_stats(repo, &oids, config.jobs)?;
+
+        // merged in commit order, whichever worker finished first
+        for (indx, stats) in uncached.into_iter().zip(computed) {
+            if let Some(file_stat_infos_str) = stats
+                .as_ref()
+                .and_then(|stats| serde_json::to_string(stats).ok())
+            {
+                info!("add {} to cache", commits[indx].id());
+                cache.set(commits[indx].id().to_string(), file_stat_infos_str);
             }
-        };
-        perf_tracker.stop();
+            file_stats[indx] = stats;
+        }
 
-        perf_tracker.start("commit :: get_stats :: compute commit diff");
-        if file_stat_infos.is_none() {
-            let mut diff_opts = DiffOptions::new();
+        Ok(file_stats)
+    }
 
-            let raw_diff = match repo.get_diff(commit, Some(&mut diff_opts)) {
-                Some(diff) => diff,
-                None => return None,
-            };
+    /// Diffs the commits against their first parent, each worker with its own repository handle.
+    fn diff_file_stats(
+        repo: &Repo,
+        oids: &[Oid],
+        jobs: usize,
+    ) -> Result<Vec<Option<Vec<FileStatInfo>>>> {
+        let jobs = jobs.clamp(1, oids.len().max(1));
+        if jobs == 1 {
+            return Ok(oids
+                .iter()
+                .map(|oid| Self::diff_file_stat_infos(repo, *oid))
+                .collect());
+        }
 
-            let diff_total: git2::DiffStats = match raw_diff.stats() {
-                Ok(diff) => diff,
-                Err(_) => return None,
-            };
+        let repos = (0..jobs)
+            .map(|_| repo.reopen())
+            .collect::<Result<Vec<Repo>>>()?;
+        let next = AtomicUsize::new(0);
+        let mut file_stats = vec![None; oids.len()];
 
-            let mutated_diff_total = diff_total
-                .to_buf(DiffStatsFormat::NUMBER, 1)
-                .map_or(String::new(), |f| f.as_str().unwrap_or("").to_string())
-                .lines()
-                .map(|line| {
-                    let normalized_line = line
-                        .split(" ")
-                        .filter(|s| !s.is_empty())
-                        .collect::<Vec<_>>()
-                        .join(" ");
-                    let normalized_line = normalized_line.split(" ").collect::<Vec<_>>();
-                    let deleted = normalized_line[1].parse::<usize>().unwrap_or(0);
-                    let inserted = normalized_line[0].parse::<usize>().unwrap_or(0);
-                    FileStatInfo {
-                        path: normalized_line[2].to_string(),
-                        inserted,
-                        deleted,
-                        total_changes: inserted as i64 + deleted as i64,
-                    }
+        thread::scope(|scope| {
+            let workers = repos
+                .into_iter()
+                .map(|repo| {
+                    let next = &next;
+                    scope.spawn(move || {
+                        let mut computed = vec![];
+                        loop {
+                            let indx = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(oid) = oids.get(indx) else {
+                                break;
+                            };
+                            computed.push((indx, Self::diff_file_stat_infos(&repo, *oid)));
+                        }
+                        computed
+                    })
                 })
-                .collect::<Vec<FileStatInfo>>();
-            if let Ok(file_stat_infos_str) = serde_json::to_string(&mutated_diff_total) {
-                info!("add {} to cache", commit.id().clone());
-                cache.set(commit.id().clone().to_string(), file_stat_infos_str);
+                .collect::<Vec<_>>();
+
+            for worker in workers {
+                for (indx, stats) in worker.join().expect("diff stats worker panicked") {
+                    file_stats[indx] = stats;
+                }
             }
+        });
 
-            file_stat_infos = Some(mutated_diff_total);
-        }
-        perf_tracker.stop();
+        Ok(file_stats)
+    }
+
+    fn diff_file_stat_infos(repo: &Repo, oid: Oid) -> Option<Vec<FileStatInfo>> {
+        let commit = repo.find_commit(oid).ok()?;
+        let mut diff_opts = DiffOptions::new();
+        let raw_diff = repo.get_diff(&commit, Some(&mut diff_opts))?;
+        let diff_total: git2::DiffStats = raw_diff.stats().ok()?;
+
+        let file_stat_infos = diff_total
+            .to_buf(DiffStatsFormat::NUMBER, 1)
+            .map_or(String::new(), |f| f.as_str().unwrap_or("").to_string())
+            .lines()
+            .map(|line| {
+                let normalized_line = line
+                    .split(" ")
+                    .filter(|s| !s.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let normalized_line = normalized_line.split(" ").collect::<Vec<_>>();
+                let deleted = normalized_line[1].parse::<usize>().unwrap_or(0);
+                let inserted = normalized_line[0].parse::<usize>().unwrap_or(0);
+                FileStatInfo {
+                    path: normalized_line[2].to_string(),
+                    inserted,
+                    deleted,
+                    total_changes: inserted as i64 + deleted as i64,
+                }
+            })
+            .collect::<Vec<FileStatInfo>>();
+
+        Some(file_stat_infos)
+    }
+
+    fn get_stats(
+        config: &Config,
+        commit: &Commit,
+        file_stat_infos: Option<Vec<FileStatInfo>>,
+    ) -> Option<Stats> {
+        let file_stat_infos = file_stat_infos?;
 
+        let mut perf_tracker = Tracker::new(
+            config,
+            Color::Cyan,
+            Some(TrackerOpts {
+                write_once: Some(true),
+            }),
+        );
         perf_tracker.start("commit :: get_stats :: filter file info by file patterns");
         let filtered_file_stat_infos = file_stat_infos
-            .unwrap()
             .into_iter()
-            .filter(|file_stat_info| {
-                match &config.filter_filename_pattern {
-                    Some(pattern) => {
-                        let pattern = format!(r"{}", pattern);
-                        let regex = Regex::new(&pattern).unwrap();
-                        if regex.is_match(&file_stat_info.path) {
-                            return true;
-                        }
-                        return false;
+            .filter(|file_stat_info| match &config.filter_filename_pattern {
+                Some(pattern) => {
+                    let pattern = pattern.to_string();
+                    let regex = Regex::new(&pattern).unwrap();
+                    if regex.is_match(&file_stat_info.path) {
+                        return true;
                     }
+                    false
+                }
 
-                    None => return true,
-                };
+                None => true,
             })
             .collect::<Vec<FileStatInfo>>();
         perf_tracker.stop();
@@ -328,10 +552,11 @@ impl CommitBucket {
         Some(stats)
     }
 
-    pub fn collect_bucket_info(commits: &Vec<CommitInfo>) -> BucketInfo {
+    pub fn collect_bucket_info(commits: &[CommitInfo], config: &Config) -> BucketInfo {
         let mut file_summs: HashMap<String, FileStatInfo> = HashMap::new();
         let mut types_count: HashMap<String, u32> = HashMap::new();
         let mut scopes_count: HashMap<String, u32> = HashMap::new();
+        let mut trailers_count: HashMap<String, u32> = HashMap::new();
         let total = commits.len();
 
         for commit in commits.iter() {
@@ -347,85 +572,50 @@ impl CommitBucket {
                 scopes_count.insert(c_scope, new_count);
             }
 
-            match &commit.stats {
-                Some(com_stat) => {
-                    for stat in com_stat.file_stat_infos.iter() {
-                        let prev_stat = file_summs.get(&stat.path);
-                        match prev_stat {
-                            Some(prev_stat) => {
-                                file_summs.insert(
-                                    prev_stat.path.clone(),
-                                    FileStatInfo {
-                                        path: prev_stat.path.clone(),
-                                        inserted: prev_stat.inserted + stat.inserted,
-                                        deleted: prev_stat.deleted + stat.deleted,
-                                        total_changes: prev_stat.total_changes + stat.total_changes,
-                                    },
-                                );
-                            }
-                            None => {
-                                file_summs.insert(
-                                    stat.path.clone(),
-                                    FileStatInfo {
-                                        path: stat.path.clone(),
-                                        inserted: stat.inserted,
-                                        deleted: stat.deleted,
-                                        total_changes: stat.total_changes,
-                                    },
-                                );
-                            }
+            for trailer in commit.trailers.iter() {
+                *trailers_count.entry(trailer.key.clone()).or_default() += 1;
+            }
+
+            if let Some(com_stat) = &commit.stats {
+                for stat in com_stat.file_stat_infos.iter() {
+                    let prev_stat = file_summs.get(&stat.path);
+                    match prev_stat {
+                        Some(prev_stat) => {
+                            file_summs.insert(
+                                prev_stat.path.clone(),
+                                FileStatInfo {
+                                    path: prev_stat.path.clone(),
+                                    inserted: prev_stat.inserted + stat.inserted,
+                                    deleted: prev_stat.deleted + stat.deleted,
+                                    total_changes: prev_stat.total_changes + stat.total_changes,
+                                },
+                            );
+                        }
+                        None => {
+                            file_summs.insert(
+                                stat.path.clone(),
+                                FileStatInfo {
+                                    path: stat.path.clone(),
+                                    inserted: stat.inserted,
+                                    deleted: stat.deleted,
+                                    total_changes: stat.total_changes,
+                                },
+                            );
                         }
                     }
                 }
-                None => {}
             };
         }
 
-        let types_total = types_count
-            .clone()
-            .into_iter()
-            .fold(0, |acc, (_, value)| acc + value);
-
-        let mut types = Freq {
-            info: HashMap::new(),
-            total: types_total,
-        };
-
-        let scopes_total = scopes_count
-            .clone()
-            .into_iter()
-            .fold(0, |acc, (_, value)| acc + value);
-
-        let mut scopes = Freq {
-            total: scopes_total,
-            info: HashMap::new(),
-        };
-
-        for (key, value) in types_count.into_iter() {
-            types.info.insert(
-                key.to_string(),
-                FreqInfo {
-                    count: value.to_owned(),
-                    freq: value as f64 / types_total as f64,
-                },
-            );
-        }
-
-        for (key, value) in scopes_count.into_iter() {
-            scopes.info.insert(
-                key.to_string(),
-                FreqInfo {
-                    count: value.to_owned(),
-                    freq: value as f64 / types_total as f64,
-                },
-            );
-        }
-
         BucketInfo {
-            types,
-            scopes,
+            types: Freq::from_counts(types_count),
+            scopes: Freq::from_counts(scopes_count),
+            trailers: Freq::from_counts(trailers_count),
             file_summs,
             total,
+            reverts: RevertSummary::collect(commits),
+            breaking: BreakingSummary::collect(commits),
+            authors: AuthorStats::collect(commits, &config.author_weighting),
         }
     }
 }
@@ -434,18 +624,98 @@ impl CommitBucket {
 mod tests {
     use tempfile::TempDir;
 
-    use crate::test_utils::mock_config;
+    use crate::authors::TopEntry;
+    use crate::config::{AuthorAlias, CommitSortType};
+    use crate::test_utils::{mock_config, mock_repo_config};
+    use clap::Parser;
 
     use super::*;
-    use crate::test_utils::setup_repo;
+    use crate::test_utils::{append_commits, setup_file_repo, setup_repo};
+
+    #[test]
+    fn can_compute_diff_stats_in_parallel() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let repo = setup_file_repo(&temp_dir);
+        let oids = repo
+            .get_commits(
+                &RevisionSelection::default(),
+                &[CommitSortType::Topological],
+            )
+            .unwrap()
+            .iter()
+            .map(|commit| commit.id())
+            .collect::<Vec<Oid>>();
+
+        let summary = |file_stats: Vec<Option<Vec<FileStatInfo>>>| {
+            file_stats
+                .into_iter()
+                .map(|stats| {
+                    stats.map(|stats| {
+                        stats
+                            .into_iter()
+                            .map(|file| (file.path, file.inserted, file.deleted))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+        let sequential = summary(CommitBucket::diff_file_stats(&repo, &oids, 1).unwrap());
+        let parallel = summary(CommitBucket::diff_file_stats(&repo, &oids, 4).unwrap());
+
+        assert_eq!(sequential.len(), 12);
+        assert_eq!(sequential, parallel);
+        assert_eq!(sequential[0], Some(vec![("f2.txt".to_string(), 3, 0)]));
+        // the root commit has no parent to diff against
+        assert!(sequential[11].is_none());
+    }
+
+    #[test]
+    fn can_build_bucket_with_any_jobs() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let repo = setup_file_repo(&temp_dir);
+
+        let build = |jobs: &str| {
+            let mut config = mock_repo_config(&temp_dir, Some(vec!["", "-j", jobs]));
+            config.cache_path = temp_dir
+                .path()
+                .join(format!("j{}.cache.json", jobs))
+                .to_string_lossy()
+                .to_string();
+            let bucket = CommitBucket::build(&repo, "type(optional_scope): description", &config)
+                .expect("Failed to build bucket");
+            let cache: HashMap<String, String> = serde_json::from_str(
+                &std::fs::read_to_string(&config.cache_path).expect("Failed to read cache"),
+            )
+            .expect("Failed to parse cache");
+            (serde_json::to_value(&bucket).unwrap(), cache)
+        };
+        let (sequential, sequential_cache) = build("1");
+        let (parallel, parallel_cache) = build("4");
+
+        assert_eq!(sequential["commits"].as_array().unwrap().len(), 12);
+        assert_eq!(sequential["commits"][0]["stats"]["insertions"], 3);
+        assert_eq!(sequential, parallel);
+        // the root commit is not diffed, so it is not cached
+        assert_eq!(sequential_cache.len(), 11);
+        assert_eq!(sequential_cache, parallel_cache);
+    }
+
+    #[test]
+    fn can_reject_zero_jobs() {
+        assert!(Config::try_parse_from(["", "-j", "0"]).is_err());
+        assert_eq!(mock_config(Some(vec!["", "-j", "4"])).jobs, 4);
+    }
 
     #[test]
     fn can_parse_commits() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
         let (repo, example_commit_message) = setup_repo(&temp_dir);
-        let bucket =
-            CommitBucket::build(&repo, example_commit_message.as_str(), &mock_config(None))
-                .expect("Failed to build commit bucket");
+        let bucket = CommitBucket::build(
+            &repo,
+            example_commit_message.as_str(),
+            &mock_repo_config(&temp_dir, None),
+        )
+        .expect("Failed to build commit bucket");
 
         assert_eq!(bucket.commits.len(), 5);
         assert_eq!(bucket.info.types.total, 4);
@@ -477,7 +747,7 @@ mod tests {
         let bucket = CommitBucket::build(
             &repo,
             example_commit_message.as_str(),
-            &mock_config(Some(vec!["", "--authors", "erencam"])),
+            &mock_repo_config(&temp_dir, Some(vec!["", "--authors", "erencam"])),
         )
         .expect("Failed to build bucket");
 
@@ -494,15 +764,18 @@ mod tests {
         let bucket = CommitBucket::build(
             &repo,
             example_commit_message.as_str(),
-            &mock_config(Some(vec![
-                "",
-                "--authors",
-                "erencam",
-                "--types",
-                "feat",
-                "-s",
-                "main",
-            ])),
+            &mock_repo_config(
+                &temp_dir,
+                Some(vec![
+                    "",
+                    "--authors",
+                    "erencam",
+                    "--types",
+                    "feat",
+                    "-s",
+                    "main",
+                ]),
+            ),
         )
         .expect("Failed to build bucket");
 
@@ -511,4 +784,256 @@ mod tests {
         assert_eq!(bucket.commits[0].type_, "feat");
         assert_eq!(bucket.commits[0].scope, "main");
     }
+
+    #[test]
+    fn can_link_reverts() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo, example_commit_message) = setup_repo(&temp_dir);
+        append_commits(
+            &temp_dir,
+            &["Revert \"feat(main): test\"\n\nIt broke main."],
+        );
+        let bucket = CommitBucket::build(
+            &repo,
+            example_commit_message.as_str(),
+            &mock_repo_config(&temp_dir, None),
+        )
+        .expect("Failed to build bucket");
+
+        let revert = &bucket.commits[0];
+        let reverted = bucket
+            .commits
+            .iter()
+            .find(|commit| commit.summary == "feat(main): test")
+            .unwrap();
+        assert_eq!(revert.type_, "revert");
+        assert_eq!(revert.scope, "main");
+        assert_eq!(
+            revert.revert_of.as_ref().unwrap().id.as_ref(),
+            Some(&reverted.id)
+        );
+
+        let reverts = &bucket.info.reverts;
+        assert_eq!(reverts.total, 1);
+        assert_eq!(reverts.reverts[0].reverted_id.as_ref(), Some(&reverted.id));
+        assert_eq!(reverts.types.get("feat").unwrap().reverted, 1);
+        assert_eq!(reverts.types.get("feat").unwrap().rate, 0.5);
+        assert_eq!(reverts.scopes.get("main").unwrap().commits, 2);
+        assert!(!reverts.types.contains_key("revert"));
+    }
+
+    #[test]
+    fn can_detect_breaking_changes() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo, example_commit_message) = setup_repo(&temp_dir);
+        append_commits(
+            &temp_dir,
+            &[
+                "feat(api)!: drop v1",
+                "refactor(cli): rename flags\n\nBREAKING CHANGE: `--out` is gone",
+                "fix(cli): typo",
+                "feat(cli)!: drop the\nold flags",
+            ],
+        );
+        let bucket = CommitBucket::build(
+            &repo,
+            example_commit_message.as_str(),
+            &mock_repo_config(&temp_dir, None),
+        )
+        .expect("Failed to build bucket");
+
+        let feat = bucket
+            .commits
+            .iter()
+            .find(|commit| commit.summary == "feat(api)!: drop v1")
+            .unwrap();
+        assert_eq!(feat.type_, "feat");
+        assert_eq!(feat.scope, "api");
+        assert!(feat.breaking);
+
+        let breaking = &bucket.info.breaking;
+        assert_eq!(breaking.total, 3);
+        assert_eq!(breaking.scopes.get("api"), Some(&1));
+        assert_eq!(breaking.scopes.get("cli"), Some(&2));
+        assert_eq!(breaking.months.values().sum::<u32>(), 3);
+        assert!(breaking
+            .changes
+            .iter()
+            .any(|change| change.description == "`--out` is gone"));
+        // a wrapped subject is parsed as a whole, like git's summary
+        assert!(breaking
+            .changes
+            .iter()
+            .any(|change| change.description == "drop the old flags"));
+    }
+
+    #[test]
+    fn can_filter_trailers() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo, example_commit_message) = setup_repo(&temp_dir);
+        append_commits(
+            &temp_dir,
+            &[
+                "feat(api): pair work\n\nWritten together.\n\nCo-authored-by: Alice <alice@example.com>\nReviewed-by: Bob <bob@example.com>",
+                "fix(api): solo work\n\nSigned-off-by: erencam <erencam.dev@gmail.com>",
+            ],
+        );
+
+        let bucket = CommitBucket::build(
+            &repo,
+            example_commit_message.as_str(),
+            &mock_repo_config(&temp_dir, None),
+        )
+        .expect("Failed to build bucket");
+        let pair = bucket
+            .commits
+            .iter()
+            .find(|commit| commit.summary == "feat(api): pair work")
+            .unwrap();
+        assert_eq!(pair.body, "Written together.");
+        assert_eq!(
+            pair.message(),
+            "feat(api): pair work\n\nWritten together.\n\nCo-authored-by: Alice <alice@example.com>\nReviewed-by: Bob <bob@example.com>"
+        );
+        assert_eq!(pair.trailers.len(), 2);
+        assert_eq!(pair.trailers[0].key, "Co-authored-by");
+        assert_eq!(bucket.info.trailers.total, 3);
+        assert_eq!(
+            bucket.info.trailers.info.get("Reviewed-by").unwrap().count,
+            1
+        );
+
+        let bucket = CommitBucket::build(
+            &repo,
+            example_commit_message.as_str(),
+            &mock_repo_config(&temp_dir, Some(vec!["", "--trailer", "Reviewed-by=bob"])),
+        )
+        .expect("Failed to build bucket");
+        assert_eq!(bucket.info.total, 1);
+        assert_eq!(bucket.commits[0].summary, "feat(api): pair work");
+    }
+
+    #[test]
+    fn can_parse_co_authors() {
+        let author = Author::parse("Alice Doe <Alice@Example.com>");
+        assert_eq!(author.name, "Alice Doe");
+        assert_eq!(author.email, "Alice@Example.com");
+        assert_eq!(author.key(), "alice@example.com");
+
+        let author = Author::parse("bob");
+        assert_eq!(author.name, "bob");
+        assert_eq!(author.key(), "bob");
+    }
+
+    #[test]
+    fn can_credit_co_authors() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo, example_commit_message) = setup_repo(&temp_dir);
+        append_commits(
+            &temp_dir,
+            &["feat(api): pair work\n\nCo-authored-by: Alice <alice@example.com>"],
+        );
+
+        let stats_of = |args: Vec<&str>| {
+            let bucket = CommitBucket::build(
+                &repo,
+                example_commit_message.as_str(),
+                &mock_repo_config(&temp_dir, Some(args)),
+            )
+            .expect("Failed to build bucket");
+            let commits_of = |name: &str| {
+                bucket
+                    .info
+                    .authors
+                    .iter()
+                    .find(|author| author.name == name)
+                    .map(|author| author.commits)
+            };
+            (
+                bucket.info.total,
+                commits_of("erencam"),
+                commits_of("Alice"),
+            )
+        };
+
+        assert_eq!(stats_of(vec![""]), (6, Some(5.0), Some(1.0)));
+        assert_eq!(
+            stats_of(vec!["", "--author-weighting", "split"]),
+            (6, Some(4.5), Some(0.5))
+        );
+        assert_eq!(
+            stats_of(vec!["", "--author-weighting", "primary"]),
+            (6, Some(5.0), None)
+        );
+        assert_eq!(
+            stats_of(vec!["", "--authors", "Alice"]),
+            (1, Some(1.0), Some(1.0))
+        );
+    }
+
+    #[test]
+    fn can_unify_author_identities() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo, example_commit_message) = setup_repo(&temp_dir);
+        std::fs::write(
+            temp_dir.path().join(".mailmap"),
+            "Eren Cam <eren@example.com> <erencam.dev@gmail.com>\n",
+        )
+        .expect("Failed to write .mailmap");
+        append_commits(
+            &temp_dir,
+            &["feat(api): pair work\n\nCo-authored-by: Eren <ERENCAM.DEV@gmail.com>\nCo-authored-by: Ghost <ghost@example.com>"],
+        );
+
+        let mut config = mock_repo_config(
+            &temp_dir,
+            Some(vec!["", "--authors", "Eren Cam", "Unknown Person"]),
+        );
+        config.author_aliases = Some(vec![AuthorAlias {
+            name: "Unknown Person".to_string(),
+            email: None,
+            aliases: vec!["unknown".to_string()],
+        }]);
+        let bucket = CommitBucket::build(&repo, example_commit_message.as_str(), &config)
+            .expect("Failed to build bucket");
+
+        assert_eq!(bucket.info.total, 6);
+        let pair = &bucket.commits[0];
+        assert_eq!(pair.author.name, "Eren Cam");
+        assert_eq!(pair.author.email, "eren@example.com");
+        assert_eq!(pair.co_authors.len(), 1);
+        assert_eq!(pair.co_authors[0].name, "Ghost");
+
+        let names = bucket
+            .info
+            .authors
+            .iter()
+            .map(|author| author.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(names, vec!["Eren Cam", "Ghost", "Unknown Person"]);
+    }
+
+    #[test]
+    fn can_aggregate_authors() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo, example_commit_message) = setup_repo(&temp_dir);
+        let bucket = CommitBucket::build(
+            &repo,
+            example_commit_message.as_str(),
+            &mock_repo_config(&temp_dir, None),
+        )
+        .expect("Failed to build bucket");
+
+        let author = &bucket.info.authors[0];
+        assert_eq!(author.name, "erencam");
+        assert_eq!(author.commits, 4.0);
+        assert_eq!(author.freq, 0.8);
+        assert_eq!(TopEntry::join(&author.top_types), "feat 2, test 2");
+        assert_eq!(
+            TopEntry::join(&author.top_scopes),
+            "main 2, commit 1, repo 1"
+        );
+        assert!(author.top_files.is_empty());
+        assert_eq!(bucket.info.authors[1].name, "unknown");
+    }
 }
@@ -1,10 +1,20 @@
+use crate::codeowners::{CodeOwners, CodeOwnersRule};
+use crate::customerror::{Error, Result};
 use crate::defaults::{
-    cache_path, convention_style, date_format, date_format_type, file_count, log_level,
-    revert_message_pattern, sort_files,
+    author_weighting, cache_path, commit_sort, convention_style, csv_tables, date_format,
+    date_format_type, jobs, log_level, max_report_size, output_type, revert_message_pattern,
+    sort_files,
+};
+use crate::ownerships::OwnedPaths;
+use clap::{
+    builder::RangedU64ValueParser, parser::ValueSource, Arg, ArgMatches, CommandFactory,
+    FromArgMatches, Parser, ValueEnum,
 };
-use clap::{Parser, ValueEnum};
 use serde::{Deserialize, Serialize};
-use std::path;
+use serde_json::{Map, Value};
+use std::{collections::BTreeMap, ffi::OsString, fs::File, path};
+
+const ENV_PREFIX: &str = "LYZE_";
 
 #[derive(Serialize, Deserialize, Debug, Parser)]
 #[serde(rename_all = "camelCase")]
@@ -14,14 +24,38 @@ pub struct Config {
     #[arg(short = 'c', long, default_value = "lyze.json")]
     pub config_path: path::PathBuf,
 
+    /// Print the effective configuration and where each value comes from, then exit
+    #[arg(long)]
+    #[serde(skip)]
+    pub print_config: bool,
+
     /// Exclude scope
     #[arg(long)]
     pub exclude_scope: bool,
 
     /// Output Type
-    #[arg(short = 'o', long, value_enum, default_value_t = OutputType::Json)]
+    #[arg(short = 'o', long, value_enum, default_value_t = output_type())]
     pub output_type: OutputType,
 
+    /// Write the report to a file instead of stdout
+    #[arg(long)]
+    pub output_file: Option<path::PathBuf>,
+
+    /// Tables written by the csv and tsv reporters
+    #[arg(long, value_enum, num_args = 1.., default_values_t = csv_tables())]
+    #[serde(default = "csv_tables")]
+    pub csv_tables: Vec<CsvTable>,
+
+    /// Maximum size in bytes of the markdown report, longer reports are truncated
+    #[arg(long, default_value_t = max_report_size())]
+    #[serde(default = "max_report_size")]
+    pub max_report_size: usize,
+
+    /// Colorize the stdout report
+    #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+    #[serde(default)]
+    pub color: ColorChoice,
+
     /// Exclude commit type
     #[arg(long)]
     pub exclude_commit_type: bool,
@@ -30,13 +64,35 @@ pub struct Config {
     #[arg(long)]
     pub path: Option<String>,
 
+    /// Analyse only the commits in the range, e.g., "v1.0.0..v1.1.0"
+    #[arg(long)]
+    pub range: Option<String>,
+
+    /// Exclude the history of the ref, e.g., the last release tag
+    #[arg(long)]
+    pub since_ref: Option<String>,
+
+    /// Revisions to walk from instead of HEAD, prefix with "^" to exclude
+    #[arg(long = "rev", value_parser, num_args=1..)]
+    pub revs: Option<Vec<String>>,
+
+    /// Walk every local branch and tag
+    #[arg(long)]
+    #[serde(default)]
+    pub all: bool,
+
+    /// Commit order, e.g., "time reverse" for oldest first
+    #[arg(long, value_enum, num_args = 1.., default_values_t = commit_sort())]
+    #[serde(default = "commit_sort")]
+    pub commit_sort: Vec<CommitSortType>,
+
     /// Convetion style
-    #[arg(short = 't', long, default_value = "type(optional_scope): description")]
+    #[arg(short = 't', long, default_value_t = convention_style())]
     #[serde(default = "convention_style")]
     pub convention_style: String,
 
     /// Revert Message Pattern
-    #[arg(long, default_value = "revert_indicator \"message\"")]
+    #[arg(long, default_value_t = revert_message_pattern())]
     #[serde(default = "revert_message_pattern")]
     pub revert_message_pattern: String,
 
@@ -52,6 +108,11 @@ pub struct Config {
     #[arg(short = 'u', long = "authors", value_parser, num_args=1..)]
     pub filter_authors: Option<Vec<String>>,
 
+    /// How co-authored commits are credited in the author statistics
+    #[arg(long, value_enum, default_value_t = author_weighting())]
+    #[serde(default = "author_weighting")]
+    pub author_weighting: AuthorWeighting,
+
     /// Filter by scope e.g., "utils"
     #[arg(short = 's', long = "scopes", value_parser, num_args=1..)]
     pub filter_scopes: Option<Vec<String>>,
@@ -60,6 +121,10 @@ pub struct Config {
     #[arg(short = 'y', long = "types", value_parser, num_args=1..)]
     pub filter_types: Option<Vec<String>>,
 
+    /// Filter by trailer, `Key` or `Key=value` e.g., "Reviewed-by=alice"; every trailer must match
+    #[arg(long = "trailer", value_parser, num_args=1..)]
+    pub filter_trailers: Option<Vec<String>>,
+
     /// Filter by start_date
     #[arg(long)]
     pub start_date: Option<String>,
@@ -69,11 +134,11 @@ pub struct Config {
     pub end_date: Option<String>,
 
     /// Date format
-    #[arg(long, default_value = "%b %e %T %Y %z")]
+    #[arg(long, default_value_t = date_format())]
     #[serde(default = "date_format")]
     pub date_format: String,
 
-    #[arg(long, value_enum, default_value_t = DateFormatType::DateTimeAndTimezone)]
+    #[arg(long, value_enum, default_value_t = date_format_type())]
     #[serde(default = "date_format_type")]
     pub date_format_type: DateFormatType,
 
@@ -82,24 +147,355 @@ pub struct Config {
     pub file_count: Option<usize>,
 
     /// File Summary, sort files by
-    #[arg(long, value_enum, default_value_t = SortType::Desc)]
+    #[arg(long, value_enum, default_value_t = sort_files())]
     #[serde(default = "sort_files")]
     pub sort_files: SortType,
 
     #[clap(skip)]
     pub ownerships: Option<Vec<OwnershipConfig>>,
 
+    /// Add the owners of the repository's CODEOWNERS file to the ownership groups
+    #[arg(long)]
+    #[serde(default)]
+    pub codeowners: bool,
+
+    /// Rules of the CODEOWNERS file, set with `--codeowners`.
+    #[clap(skip)]
+    #[serde(skip)]
+    pub codeowners_rules: Vec<CodeOwnersRule>,
+
+    /// Add a pattern to an ownership group, `NAME=PATTERN` e.g., "web=^web/"; "!" disowns
+    #[arg(long = "ownership", value_parser, num_args=1..)]
+    pub ownership_patterns: Option<Vec<String>>,
+
+    /// Add a member to an ownership group, `NAME=AUTHOR` with the author's name or email
+    #[arg(long = "ownership-author", value_parser, num_args=1..)]
+    pub ownership_authors: Option<Vec<String>>,
+
+    /// Report only the named ownership groups
+    #[arg(long = "ownership-groups", value_parser, num_args=1..)]
+    pub ownership_groups: Option<Vec<String>>,
+
+    #[clap(skip)]
+    pub author_aliases: Option<Vec<AuthorAlias>>,
+
     /// Cache path
-    #[arg(long, default_value = ".lyze.cache.json")]
+    #[arg(long, default_value_t = cache_path())]
     #[serde(default = "cache_path")]
     pub cache_path: String,
 
+    /// Workers computing the diff stats of uncached commits, defaults to the available cores
+    #[arg(
+        short = 'j',
+        long,
+        default_value_t = jobs(),
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    #[serde(default = "jobs")]
+    pub jobs: usize,
+
+    /// Keys of the config file that match no option, reported once the config is loaded.
+    #[clap(skip)]
+    #[serde(skip)]
+    pub unknown_keys: Vec<String>,
+
     /// Log Level
-    #[arg(long, value_enum, default_value_t = LogLevel::Off)]
+    #[arg(long, value_enum, default_value_t = log_level())]
     #[serde(default = "log_level")]
     pub log_level: LogLevel,
 }
 
+/// Configuration layer a value was taken from, later layers win.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConfigSource {
+    Default,
+    File,
+    Env,
+    Cli,
+}
+
+pub type ConfigSources = BTreeMap<String, ConfigSource>;
+
+impl Config {
+    /// Loads the configuration from the defaults, the config file, `LYZE_*` environment
+    /// variables and the command line, in that order.
+    pub fn load() -> Result<(Config, ConfigSources)> {
+        Self::load_from(std::env::args_os(), |name| std::env::var(name).ok())
+    }
+
+    /// Same as `load`, with the environment variables looked up through `env`.
+    pub fn load_from<I, T>(
+        args: I,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<(Config, ConfigSources)>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let command = Config::command().mut_args(|arg| match env_name(&arg) {
+            Some(name) => {
+                let help = match arg.get_help() {
+                    Some(help) => format!("{} [env: {}]", help, name),
+                    None => format!("[env: {}]", name),
+                };
+                arg.help(help)
+            }
+            None => arg,
+        });
+        let matches = command.clone().try_get_matches_from(args)?;
+        let args_config = Config::from_arg_matches(&matches)?;
+
+        // the variables are parsed as the flags they are named after, e.g., `--file-count=10`
+        let mut env_args = vec![String::new()];
+        for arg in command.get_arguments() {
+            let (Some(long), Some(value)) = (arg.get_long(), env_name(arg).and_then(|n| env(&n)))
+            else {
+                continue;
+            };
+            if arg.get_action().takes_values() {
+                env_args.push(format!("--{}={}", long, value));
+            } else if !matches!(
+                value.to_lowercase().as_str(),
+                "" | "0" | "false" | "no" | "off"
+            ) {
+                env_args.push(format!("--{}", long));
+            }
+        }
+        let env_matches = command.try_get_matches_from(env_args)?;
+        let env_config = Config::from_arg_matches(&env_matches)?;
+
+        let given = |matches: &ArgMatches, id: &str| {
+            matches.value_source(id) == Some(ValueSource::CommandLine)
+        };
+        let config_path = match given(&matches, "config_path") {
+            true => &args_config.config_path,
+            false => &env_config.config_path,
+        };
+
+        let mut merged = match serde_json::to_value(Config::try_parse_from([""])?)? {
+            Value::Object(map) => map,
+            _ => Map::new(),
+        };
+        let mut sources: ConfigSources = merged
+            .keys()
+            .map(|key| (key.clone(), ConfigSource::Default))
+            .collect();
+
+        let mut unknown_keys = vec![];
+        if let Ok(config_file) = File::open(config_path) {
+            let file_values: Map<String, Value> = serde_json::from_reader(config_file)?;
+            for (key, value) in file_values {
+                if !merged.contains_key(&key) {
+                    unknown_keys.push(key);
+                    continue;
+                }
+                merged.insert(key.clone(), value);
+                sources.insert(key, ConfigSource::File);
+            }
+        }
+
+        for (layer, matches, layer_config) in [
+            (ConfigSource::Env, &env_matches, &env_config),
+            (ConfigSource::Cli, &matches, &args_config),
+        ] {
+            let values = serde_json::to_value(layer_config)?;
+            for id in matches.ids() {
+                if !given(matches, id.as_str()) {
+                    continue;
+                }
+                let key = to_camel_case(id.as_str());
+                if let Some(value) = values.get(&key) {
+                    merged.insert(key.clone(), value.clone());
+                    sources.insert(key, layer);
+                }
+            }
+        }
+
+        let mut config: Config = serde_json::from_value(Value::Object(merged))?;
+        config.print_config = args_config.print_config;
+        config.unknown_keys = unknown_keys;
+
+        Ok((config, sources))
+    }
+
+    /// Adds the owners of CODEOWNERS to `ownerships`. An owner named like a configured group is
+    /// merged into it, the group then owns its own patterns and the paths of the CODEOWNERS rules.
+    pub fn merge_codeowners(&mut self, codeowners: CodeOwners) {
+        let ownerships = self.ownerships.get_or_insert_with(Vec::new);
+        for owner in codeowners.groups {
+            match ownerships.iter_mut().find(|group| group.name == owner.name) {
+                Some(group) => {
+                    for author in owner.authors {
+                        if !group.authors.contains(&author) {
+                            group.authors.push(author);
+                        }
+                    }
+                }
+                None => ownerships.push(owner),
+            }
+        }
+        self.codeowners_rules = codeowners.rules;
+    }
+
+    /// Adds the groups of `--ownership` and `--ownership-author` to `ownerships`, then keeps the
+    /// ones named by `--ownership-groups`.
+    pub fn merge_ownership_args(&mut self) -> Result<()> {
+        let patterns = self
+            .ownership_patterns
+            .iter()
+            .flatten()
+            .map(|arg| (arg, false));
+        let authors = self
+            .ownership_authors
+            .iter()
+            .flatten()
+            .map(|arg| (arg, true));
+
+        for (arg, is_author) in patterns.chain(authors) {
+            let Some((name, value)) = arg
+                .split_once('=')
+                .filter(|(name, value)| !name.is_empty() && !value.is_empty())
+            else {
+                return Err(Error::ArgError(clap::Error::raw(
+                    clap::error::ErrorKind::InvalidValue,
+                    format!("invalid ownership `{}`, expected NAME=VALUE\n", arg),
+                )));
+            };
+
+            let ownerships = self.ownerships.get_or_insert_with(Vec::new);
+            let indx = match ownerships.iter().position(|group| group.name == name) {
+                Some(indx) => indx,
+                None => {
+                    ownerships.push(OwnershipConfig {
+                        name: name.to_string(),
+                        patterns: vec![],
+                        authors: vec![],
+                    });
+                    ownerships.len() - 1
+                }
+            };
+            match is_author {
+                true => ownerships[indx].authors.push(value.to_string()),
+                false => ownerships[indx].patterns.push(value.to_string()),
+            }
+        }
+
+        if let Some(names) = &self.ownership_groups {
+            let ownerships = self.ownerships.take().unwrap_or_default();
+            if let Some(unknown) = names
+                .iter()
+                .find(|name| !ownerships.iter().any(|group| &&group.name == name))
+            {
+                return Err(Error::ArgError(clap::Error::raw(
+                    clap::error::ErrorKind::InvalidValue,
+                    format!(
+                        "unknown ownership group `{}`, expected one of: {}\n",
+                        unknown,
+                        ownerships
+                            .iter()
+                            .map(|group| group.name.as_str())
+                            .collect::<Vec<&str>>()
+                            .join(", ")
+                    ),
+                )));
+            }
+            self.ownerships = Some(
+                ownerships
+                    .into_iter()
+                    .filter(|group| names.contains(&group.name))
+                    .collect(),
+            );
+        }
+
+        for group in self.ownerships.iter().flatten() {
+            if let Err(Error::ParseError(message)) = OwnedPaths::new(group) {
+                return Err(Error::ArgError(clap::Error::raw(
+                    clap::error::ErrorKind::InvalidValue,
+                    format!("{}\n", message),
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Filters given on the command line or in the config file, e.g., `types: feat, fix`.
+    pub fn active_filters(&self) -> Vec<String> {
+        let mut filters = vec![];
+        for (name, values) in [
+            ("types", &self.filter_types),
+            ("scopes", &self.filter_scopes),
+            ("authors", &self.filter_authors),
+            ("trailers", &self.filter_trailers),
+            ("excluded files", &self.exclude_filename_patterns),
+        ] {
+            if let Some(values) = values.as_ref().filter(|values| !values.is_empty()) {
+                filters.push(format!("{}: {}", name, values.join(", ")));
+            }
+        }
+        for (name, value) in [
+            ("files", &self.filter_filename_pattern),
+            ("from", &self.start_date),
+            ("to", &self.end_date),
+        ] {
+            if let Some(value) = value {
+                filters.push(format!("{}: {}", name, value));
+            }
+        }
+
+        filters
+    }
+
+    /// Renders every value of the configuration with the layer it comes from.
+    pub fn describe(&self, sources: &ConfigSources) -> Result<String> {
+        let values = serde_json::to_value(self)?;
+        let Value::Object(values) = values else {
+            return Err(Error::ParseError("config is not an object".to_string()));
+        };
+
+        let width = values.keys().map(|key| key.len()).max().unwrap_or(0);
+        Ok(values
+            .iter()
+            .map(|(key, value)| {
+                let source = sources.get(key).copied().unwrap_or(ConfigSource::Default);
+                format!(
+                    "{:<width$}  {}  ({})\n",
+                    key,
+                    value,
+                    format!("{:?}", source).to_lowercase(),
+                    width = width
+                )
+            })
+            .collect())
+    }
+}
+
+/// `LYZE_*` variable of a long flag, e.g., `LYZE_FILE_COUNT` for `--file-count`.
+fn env_name(arg: &Arg) -> Option<String> {
+    match arg.get_long() {
+        Some(long) if !matches!(long, "help" | "version" | "print-config") => Some(format!(
+            "{}{}",
+            ENV_PREFIX,
+            long.to_uppercase().replace('-', "_")
+        )),
+        _ => None,
+    }
+}
+
+fn to_camel_case(snake_case: &str) -> String {
+    let mut parts = snake_case.split('_');
+    let first = parts.next().unwrap_or("").to_string();
+    parts.fold(first, |mut acc, part| {
+        let mut chars = part.chars();
+        if let Some(c) = chars.next() {
+            acc.extend(c.to_uppercase());
+            acc.push_str(chars.as_str());
+        }
+        acc
+    })
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OwnershipConfig {
@@ -108,6 +504,16 @@ pub struct OwnershipConfig {
     pub authors: Vec<String>,
 }
 
+/// Canonical identity for the names and emails listed in `aliases`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorAlias {
+    pub name: String,
+    pub email: Option<String>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
 #[derive(ValueEnum, Serialize, Deserialize, Clone, Debug)]
 pub enum DateFormatType {
     DateOnly,
@@ -115,21 +521,55 @@ pub enum DateFormatType {
     DateTimeAndTimezone,
 }
 
-#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug)]
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum CommitSortType {
+    Topological,
+    Time,
+    AuthorTime,
+    Reverse,
+}
+
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, Default)]
 pub enum SortType {
     Asc,
+    #[default]
     Desc,
 }
 
-impl Default for SortType {
-    fn default() -> Self {
-        SortType::Desc
-    }
+/// `full` credits every participant with the whole commit, `split` shares it evenly and
+/// `primary` ignores `Co-authored-by` trailers.
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum AuthorWeighting {
+    Full,
+    Split,
+    Primary,
 }
+
 #[derive(ValueEnum, Serialize, Deserialize, Clone, Debug)]
 pub enum OutputType {
     Json,
     Stdout,
+    Tui,
+    Csv,
+    Tsv,
+    Markdown,
+    Html,
+}
+
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum CsvTable {
+    Commits,
+    Files,
+    CommitFiles,
+    Authors,
+}
+
+#[derive(ValueEnum, Serialize, Deserialize, Clone, Debug, Default)]
+pub enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
 }
 
 #[derive(ValueEnum, Serialize, Deserialize, Clone, Debug)]
@@ -142,3 +582,130 @@ pub enum LogLevel {
     Performance,
     Off,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn can_merge_config_layers() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config_path = temp_dir.path().join("lyze.json");
+        fs::write(
+            &config_path,
+            r#"{ "fileCount": 3, "sortFiles": "Asc", "outputType": "Markdown", "fileCont": 4 }"#,
+        )
+        .expect("Failed to write config file");
+        let env = |name: &str| match name {
+            "LYZE_MAX_REPORT_SIZE" => Some("1234".to_string()),
+            "LYZE_OUTPUT_TYPE" => Some("json".to_string()),
+            _ => None,
+        };
+
+        let (config, sources) = Config::load_from(
+            ["", "-c", config_path.to_str().unwrap(), "-o", "stdout"],
+            env,
+        )
+        .expect("Failed to load config");
+
+        assert_eq!(config.file_count, Some(3));
+        assert_eq!(config.unknown_keys, vec!["fileCont"]);
+        assert!(matches!(config.sort_files, SortType::Asc));
+        assert!(matches!(config.output_type, OutputType::Stdout));
+        assert_eq!(config.max_report_size, 1234);
+        assert_eq!(config.date_format, date_format());
+
+        assert_eq!(sources.get("fileCount"), Some(&ConfigSource::File));
+        assert_eq!(sources.get("outputType"), Some(&ConfigSource::Cli));
+        assert_eq!(sources.get("maxReportSize"), Some(&ConfigSource::Env));
+        assert_eq!(sources.get("dateFormat"), Some(&ConfigSource::Default));
+        assert_eq!(sources.get("configPath"), Some(&ConfigSource::Cli));
+
+        let config_path = config_path.to_string_lossy().to_string();
+        let (config, sources) = Config::load_from([""], |name| {
+            (name == "LYZE_CONFIG_PATH").then(|| config_path.clone())
+        })
+        .expect("Failed to load config");
+        assert!(matches!(config.output_type, OutputType::Markdown));
+        assert_eq!(sources.get("configPath"), Some(&ConfigSource::Env));
+    }
+
+    #[test]
+    fn can_list_active_filters() {
+        let config =
+            Config::try_parse_from(["", "-y", "feat", "fix", "--start-date", "2024-01-01"])
+                .expect("Failed to parse config");
+        assert_eq!(
+            config.active_filters(),
+            vec!["types: feat, fix", "from: 2024-01-01"]
+        );
+    }
+
+    #[test]
+    fn can_merge_ownership_args() {
+        let mut config = Config::try_parse_from([
+            "",
+            "--ownership",
+            "web=^web/",
+            "api=^api/",
+            "web=!^web/vendor/",
+            "--ownership-author",
+            "web=alice",
+            "--ownership-groups",
+            "web",
+        ])
+        .expect("Failed to parse config");
+        config.merge_ownership_args().expect("Failed to merge");
+
+        let ownerships = config.ownerships.unwrap();
+        assert_eq!(ownerships.len(), 1);
+        assert_eq!(ownerships[0].patterns, vec!["^web/", "!^web/vendor/"]);
+        assert_eq!(ownerships[0].authors, vec!["alice"]);
+
+        let mut config = Config::try_parse_from(["", "--ownership-groups", "web"]).unwrap();
+        assert!(config.merge_ownership_args().is_err());
+        let mut config = Config::try_parse_from(["", "--ownership", "web"]).unwrap();
+        assert!(config.merge_ownership_args().is_err());
+        let mut config = Config::try_parse_from(["", "--ownership", "web=("]).unwrap();
+        assert!(matches!(
+            config.merge_ownership_args(),
+            Err(Error::ArgError(e)) if e.kind() == clap::error::ErrorKind::InvalidValue
+        ));
+    }
+
+    #[test]
+    fn can_merge_codeowners() {
+        let mut config = Config::try_parse_from([
+            "",
+            "--ownership",
+            "@alice=^docs/",
+            "--ownership",
+            "web=^web/",
+        ])
+        .expect("Failed to parse config");
+        config.merge_ownership_args().expect("Failed to merge");
+        config.merge_codeowners(
+            crate::codeowners::parse("api/ @alice @org/api\n").expect("Failed to parse"),
+        );
+
+        let ownerships = config.ownerships.unwrap();
+        let names = ownerships
+            .iter()
+            .map(|group| group.name.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(names, vec!["@alice", "web", "@org/api"]);
+        assert_eq!(ownerships[0].patterns, vec!["^docs/"]);
+        assert_eq!(ownerships[0].authors, vec!["alice"]);
+        assert_eq!(config.codeowners_rules.len(), 1);
+    }
+
+    #[test]
+    fn can_convert_to_camel_case() {
+        assert_eq!(to_camel_case("filter_authors"), "filterAuthors");
+        assert_eq!(to_camel_case("all"), "all");
+    }
+}
@@ -3,6 +3,7 @@ use regex::Regex;
 #[derive(Debug)]
 pub struct ConventionBuilder {
     regex: Regex,
+    breaking_footer_regex: Regex,
 }
 
 #[derive(Debug, Default)]
@@ -10,6 +11,8 @@ pub struct ParsedCommitInfo {
     pub type_: String,
     pub optional_scope: Option<String>,
     pub description: Option<String>,
+    pub breaking: bool,
+    pub breaking_description: Option<String>,
 }
 
 const OPTIONAL_SCOPE_INDICATOR: &str = "optional_scope";
@@ -24,25 +27,54 @@ impl ConventionBuilder {
             example_commit_message.as_bytes()[OPTIONAL_SCOPE_INDICATOR.len() + indx] as char,
         );
 
-        let regex_pattern = format!(r"^(.*?)(?:\{}(.*?)\{})?: (.*)$", indicators.0, indicators.1);
+        let regex_pattern = format!(
+            r"^(.*?)(?:\{}(.*?)\{})?(!)?: (.*)$",
+            indicators.0, indicators.1
+        );
 
         ConventionBuilder {
             regex: Regex::new(&regex_pattern).unwrap(),
+            breaking_footer_regex: Regex::new(r"(?m)^BREAKING[ -]CHANGE: ?(.*)$").unwrap(),
         }
     }
 
-    pub fn construct_info(&self, message: String) -> Option<ParsedCommitInfo> {
-        let captures = self.regex.captures(&message);
+    /// Parses the summary, and the footers of the body for a `BREAKING CHANGE:`.
+    pub fn construct_info(&self, summary: &str, body: &str) -> Option<ParsedCommitInfo> {
+        let captures = self.regex.captures(summary.trim_end());
+
+        captures.map(|captures| {
+            let description = captures.get(4).map(|m| m.as_str().to_string());
+            let footer = self.breaking_footer(body);
+            let breaking = captures.get(3).is_some() || footer.is_some();
 
-        match captures {
-            Some(captures) => Some(ParsedCommitInfo {
+            ParsedCommitInfo {
                 type_: captures.get(1).unwrap().as_str().to_string(),
                 optional_scope: captures.get(2).map(|m| m.as_str().to_string()),
-                description: captures.get(3).map(|m| m.as_str().to_string()),
-            }),
+                breaking,
+                breaking_description: match breaking {
+                    true => footer.or(description.clone()),
+                    false => None,
+                },
+                description,
+            }
+        })
+    }
 
-            None => None,
-        }
+    /// The `BREAKING CHANGE:` footer, continued until the end of its paragraph.
+    fn breaking_footer(&self, body: &str) -> Option<String> {
+        let captures = self.breaking_footer_regex.captures(body)?;
+        let start = captures.get(0).unwrap().end();
+
+        let mut description = vec![captures.get(1).unwrap().as_str().trim()];
+        description.extend(
+            body[start..]
+                .lines()
+                .skip(1)
+                .take_while(|line| !line.trim().is_empty())
+                .map(|line| line.trim()),
+        );
+
+        Some(description.join(" ").trim().to_string())
     }
 }
 
@@ -55,7 +87,7 @@ mod tests {
         let example_commit_message = String::from("type(optional_scope): description");
         let style_builder = ConventionBuilder::build(example_commit_message.as_str());
         let parsed_info = style_builder
-            .construct_info(String::from("ci(frontend): build times"))
+            .construct_info("ci(frontend): build times", "")
             .unwrap();
         assert_eq!(parsed_info.type_, "ci");
         assert_eq!(parsed_info.optional_scope.unwrap(), "frontend");
@@ -66,19 +98,60 @@ mod tests {
     fn can_construct_commit_message_without_optional_scope() {
         let example_commit_message = String::from("type(optional_scope): description");
         let style_builder = ConventionBuilder::build(example_commit_message.as_str());
-        let parsed_info = style_builder
-            .construct_info(String::from("ci: build times"))
-            .unwrap();
+        let parsed_info = style_builder.construct_info("ci: build times", "").unwrap();
         assert_eq!(parsed_info.type_, "ci");
         assert_eq!(parsed_info.optional_scope, None);
         assert_eq!(parsed_info.description.unwrap(), "build times");
     }
 
+    #[test]
+    fn can_construct_breaking_change() {
+        let example_commit_message = String::from("type(optional_scope): description");
+        let style_builder = ConventionBuilder::build(example_commit_message.as_str());
+
+        let parsed_info = style_builder
+            .construct_info("feat(api)!: drop v1 endpoints", "")
+            .unwrap();
+        assert_eq!(parsed_info.type_, "feat");
+        assert_eq!(parsed_info.optional_scope.unwrap(), "api");
+        assert!(parsed_info.breaking);
+        assert_eq!(
+            parsed_info.breaking_description.unwrap(),
+            "drop v1 endpoints"
+        );
+
+        let parsed_info = style_builder
+            .construct_info("feat!: new config", "")
+            .unwrap();
+        assert_eq!(parsed_info.type_, "feat");
+        assert_eq!(parsed_info.optional_scope, None);
+        assert!(parsed_info.breaking);
+
+        let parsed_info = style_builder
+            .construct_info(
+                "refactor(cli): rename flags",
+                "Body.\n\nBREAKING CHANGE: `--out` is\nnow `--output-file`\n\nRefs: #12",
+            )
+            .unwrap();
+        assert_eq!(parsed_info.type_, "refactor");
+        assert!(parsed_info.breaking);
+        assert_eq!(
+            parsed_info.breaking_description.unwrap(),
+            "`--out` is now `--output-file`"
+        );
+
+        let parsed_info = style_builder
+            .construct_info("fix(cli): typo", "No breaking change here.")
+            .unwrap();
+        assert!(!parsed_info.breaking);
+        assert_eq!(parsed_info.breaking_description, None);
+    }
+
     #[test]
     fn should_skip_non_conventional_commit() {
         let example_commit_message = String::from("type(optional_scope): description");
         let style_builder = ConventionBuilder::build(example_commit_message.as_str());
-        let parsed_info = style_builder.construct_info(String::from("init"));
+        let parsed_info = style_builder.construct_info("init", "");
         assert!(parsed_info.is_none());
     }
 }
@@ -16,6 +16,9 @@ pub enum Error {
     #[error("Serde Json error: `${0}`")]
     SerdeError(#[from] serde_json::Error),
 
+    #[error("Argument error: `${0}`")]
+    ArgError(#[from] clap::Error),
+
     #[error("Thread err: `${0}`")]
     RecvError(#[from] RecvError),
 }
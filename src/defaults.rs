@@ -1,11 +1,13 @@
-use crate::config::{DateFormatType, LogLevel, OutputType, SortType};
+use crate::config::{
+    AuthorWeighting, CommitSortType, CsvTable, DateFormatType, LogLevel, OutputType, SortType,
+};
 
 pub fn convention_style() -> String {
     "type(optional_scope): description".to_string()
 }
 
 pub fn date_format() -> String {
-    "%a %b %e %T %Y %z".to_string()
+    "%b %e %T %Y %z".to_string()
 }
 
 pub fn date_format_type() -> DateFormatType {
@@ -16,16 +18,36 @@ pub fn file_count() -> usize {
     10
 }
 
+pub fn commit_sort() -> Vec<CommitSortType> {
+    vec![CommitSortType::Topological]
+}
+
 pub fn sort_files() -> SortType {
     SortType::Desc
 }
 
+pub fn author_weighting() -> AuthorWeighting {
+    AuthorWeighting::Full
+}
+
 pub fn output_type() -> OutputType {
     OutputType::Json
 }
 
+pub fn csv_tables() -> Vec<CsvTable> {
+    vec![CsvTable::Commits]
+}
+
+pub fn max_report_size() -> usize {
+    65_000
+}
+
 pub fn cache_path() -> String {
-    ".lyze.config.json".to_string()
+    ".lyze.cache.json".to_string()
+}
+
+pub fn jobs() -> usize {
+    std::thread::available_parallelism().map_or(1, |cores| cores.get())
 }
 
 pub fn revert_message_pattern() -> String {
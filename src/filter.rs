@@ -0,0 +1,181 @@
+use chrono::NaiveDate;
+
+use crate::{
+    commit::CommitInfo,
+    customerror::{Error, Result},
+    message::TrailerFilter,
+};
+
+/// Filter typed in the tui, e.g., `type:feat author:alice path:src/`.
+///
+/// Terms with the same key match any of their values, terms with different keys must all
+/// match. A term without a key is searched in the summary.
+#[derive(Debug, Default, Clone)]
+pub struct CommitFilter {
+    pub types: Vec<String>,
+    pub scopes: Vec<String>,
+    pub authors: Vec<String>,
+    pub paths: Vec<String>,
+    pub trailers: Vec<TrailerFilter>,
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+    pub text: Vec<String>,
+}
+
+impl CommitFilter {
+    pub fn parse(expression: &str) -> Result<CommitFilter> {
+        let mut filter = CommitFilter::default();
+
+        for term in expression.split_whitespace() {
+            let Some((key, value)) = term.split_once(':') else {
+                filter.text.push(term.to_lowercase());
+                continue;
+            };
+            match key {
+                "type" => filter.types.push(value.to_string()),
+                "scope" => filter.scopes.push(value.to_string()),
+                "author" => filter.authors.push(value.to_lowercase()),
+                "path" => filter.paths.push(value.to_string()),
+                "trailer" => filter.trailers.push(TrailerFilter::parse(value)?),
+                "since" => filter.since = Some(parse_day(value)?),
+                "until" => filter.until = Some(parse_day(value)? + 86_399),
+                _ => {
+                    return Err(Error::ParseError(format!(
+                        "unknown filter `{}`, expected type, scope, author, path, trailer, since or until",
+                        key
+                    )))
+                }
+            }
+        }
+
+        Ok(filter)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+            && self.scopes.is_empty()
+            && self.authors.is_empty()
+            && self.paths.is_empty()
+            && self.trailers.is_empty()
+            && self.since.is_none()
+            && self.until.is_none()
+            && self.text.is_empty()
+    }
+
+    pub fn matches(&self, commit: &CommitInfo) -> bool {
+        let summary = commit.summary.to_lowercase();
+
+        (self.types.is_empty() || self.types.contains(&commit.type_))
+            && (self.scopes.is_empty() || self.scopes.contains(&commit.scope))
+            && (self.authors.is_empty()
+                || commit.participants().iter().any(|author| {
+                    self.authors.iter().any(|filter| {
+                        author.name.to_lowercase().contains(filter)
+                            || author.email.to_lowercase().contains(filter)
+                    })
+                }))
+            && (self.paths.is_empty()
+                || commit.stats.as_ref().is_some_and(|stats| {
+                    stats
+                        .file_stat_infos
+                        .iter()
+                        .any(|file_stat_info| self.matches_path(&file_stat_info.path))
+                }))
+            && self
+                .trailers
+                .iter()
+                .all(|filter| filter.matches(&commit.trailers))
+            && self.since.is_none_or(|since| commit.time >= since)
+            && self.until.is_none_or(|until| commit.time <= until)
+            && self.text.iter().all(|text| summary.contains(text))
+    }
+
+    fn matches_path(&self, path: &str) -> bool {
+        self.paths.is_empty() || self.paths.iter().any(|prefix| path.starts_with(prefix))
+    }
+
+    /// Keeps the matching commits, their stats are narrowed to the files under `path:`.
+    pub fn apply(&self, commits: &[CommitInfo]) -> Vec<CommitInfo> {
+        commits
+            .iter()
+            .filter(|commit| self.matches(commit))
+            .map(|commit| match self.paths.is_empty() {
+                true => commit.clone(),
+                false => {
+                    commit.with_files(|file_stat_info| self.matches_path(&file_stat_info.path))
+                }
+            })
+            .collect()
+    }
+}
+
+fn parse_day(value: &str) -> Result<i64> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|day| day.and_hms_opt(0, 0, 0))
+        .map(|time| time.and_utc().timestamp())
+        .ok_or(Error::ParseError(format!(
+            "invalid date `{}`, expected YYYY-MM-DD",
+            value
+        )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commit::{Author, FileStatInfo, Stats};
+
+    fn commit(type_: &str, author: &str, paths: &[&str]) -> CommitInfo {
+        CommitInfo {
+            summary: format!("{}: change {}", type_, paths.join(" ")),
+            type_: type_.to_string(),
+            author: Author {
+                name: author.to_string(),
+                email: format!("{}@example.com", author),
+            },
+            stats: Some(Stats {
+                file_stat_infos: paths
+                    .iter()
+                    .map(|path| FileStatInfo {
+                        path: path.to_string(),
+                        inserted: 1,
+                        deleted: 1,
+                        total_changes: 2,
+                    })
+                    .collect(),
+                ..Default::default()
+            }),
+            time: 1_700_000_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn can_filter_commits() {
+        let commits = vec![
+            commit("feat", "alice", &["src/a.rs", "README.md"]),
+            commit("fix", "alice", &["src/b.rs"]),
+            commit("feat", "bob", &["docs/c.md"]),
+        ];
+
+        let filtered = CommitFilter::parse("type:feat author:Alice path:src/")
+            .unwrap()
+            .apply(&commits);
+        assert_eq!(filtered.len(), 1);
+        let stats = filtered[0].stats.as_ref().unwrap();
+        assert_eq!(stats.changed_files_count, 1);
+        assert_eq!(stats.insertions, 1);
+
+        let filter = CommitFilter::parse("type:feat type:fix").unwrap();
+        assert_eq!(filter.apply(&commits).len(), 3);
+
+        let filter = CommitFilter::parse("CHANGE docs until:2023-11-14").unwrap();
+        assert_eq!(filter.apply(&commits).len(), 1);
+        let filter = CommitFilter::parse("since:2023-11-15").unwrap();
+        assert!(filter.apply(&commits).is_empty());
+
+        assert!(CommitFilter::parse("").unwrap().is_empty());
+        assert!(CommitFilter::parse("colour:red").is_err());
+        assert!(CommitFilter::parse("since:yesterday").is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use git2::{Mailmap, Signature, Time};
+use log::debug;
+
+use crate::{commit::Author, config::AuthorAlias};
+
+/// Maps the names and emails an author commits under to one canonical `Author`.
+pub struct IdentityResolver<'a> {
+    mailmap: Option<Mailmap>,
+    aliases: &'a [AuthorAlias],
+}
+
+impl<'a> IdentityResolver<'a> {
+    pub fn new(mailmap: Option<Mailmap>, aliases: &'a [AuthorAlias]) -> Self {
+        IdentityResolver { mailmap, aliases }
+    }
+
+    /// Applies the `.mailmap` first, then the `authorAliases` of the config.
+    pub fn resolve(&self, author: Author) -> Author {
+        let author = match &self.mailmap {
+            Some(mailmap) => resolve_mailmap(mailmap, author, None),
+            None => author,
+        };
+        self.resolve_alias(author)
+    }
+
+    /// Like `resolve`, for the signature of a commit, which the `.mailmap` can always look up.
+    pub fn resolve_signature(&self, signature: &Signature) -> Author {
+        let author = Author {
+            name: signature.name().unwrap_or("").to_string(),
+            email: signature.email().unwrap_or("").to_string(),
+        };
+        let author = match &self.mailmap {
+            Some(mailmap) => resolve_mailmap(mailmap, author, Some(signature)),
+            None => author,
+        };
+        self.resolve_alias(author)
+    }
+
+    fn resolve_alias(&self, author: Author) -> Author {
+        let alias = self.aliases.iter().find(|alias| {
+            alias.aliases.iter().chain([&alias.name]).any(|identity| {
+                identity.eq_ignore_ascii_case(&author.name)
+                    || (!author.email.is_empty() && identity.eq_ignore_ascii_case(&author.email))
+            }) || alias
+                .email
+                .as_ref()
+                .is_some_and(|email| email.eq_ignore_ascii_case(&author.email))
+        });
+
+        match alias {
+            Some(alias) => Author {
+                name: alias.name.clone(),
+                email: alias.email.clone().unwrap_or(author.email),
+            },
+            None => author,
+        }
+    }
+}
+
+fn resolve_mailmap(mailmap: &Mailmap, author: Author, signature: Option<&Signature>) -> Author {
+    if let Some(resolved) = signature
+        .and_then(|signature| mailmap.resolve_signature(signature).ok())
+        .and_then(|resolved| changed(&resolved, &author.name, &author.email))
+    {
+        return resolved;
+    }
+
+    // git matches mailmap emails ignoring case, libgit2 does not
+    for email in [author.email.clone(), author.email.to_lowercase()] {
+        // libgit2 refuses empty names, and `<` or `>` in names and emails
+        let signature = match Signature::new(&author.name, &email, &Time::new(0, 0)) {
+            Ok(signature) => signature,
+            Err(e) => {
+                debug!(
+                    "skipping the mailmap for {} <{}>: {}",
+                    author.name,
+                    email,
+                    e.message()
+                );
+                continue;
+            }
+        };
+        if let Some(resolved) = mailmap
+            .resolve_signature(&signature)
+            .ok()
+            .and_then(|resolved| changed(&resolved, &author.name, &email))
+        {
+            return resolved;
+        }
+    }
+
+    author
+}
+
+/// The resolved author, if the mailmap maps `name` and `email` to another identity.
+fn changed(resolved: &Signature, name: &str, email: &str) -> Option<Author> {
+    let (resolved_name, resolved_email) = (
+        resolved.name().unwrap_or(""),
+        resolved.email().unwrap_or(""),
+    );
+    (resolved_name != name || resolved_email != email).then(|| Author {
+        name: resolved_name.to_string(),
+        email: resolved_email.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_resolve_aliases() {
+        let aliases = vec![AuthorAlias {
+            name: "Alice Doe".to_string(),
+            email: Some("alice@example.com".to_string()),
+            aliases: vec!["alice".to_string(), "adoe@old.example.com".to_string()],
+        }];
+        let resolver = IdentityResolver::new(None, &aliases);
+
+        for (name, email) in [
+            ("alice", "alice@laptop.local"),
+            ("A. Doe", "ADoe@old.example.com"),
+            ("Alice Doe", ""),
+        ] {
+            let author = resolver.resolve(Author {
+                name: name.to_string(),
+                email: email.to_string(),
+            });
+            assert_eq!(author.name, "Alice Doe");
+            assert_eq!(author.email, "alice@example.com");
+        }
+
+        let author = resolver.resolve(Author {
+            name: "bob".to_string(),
+            email: "bob@example.com".to_string(),
+        });
+        assert_eq!(author.name, "bob");
+    }
+
+    #[test]
+    fn can_map_signatures_git_cannot_build() {
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let repo = git2::Repository::init(temp_dir.path()).expect("Failed to init repository");
+        // libgit2 builds no signature with an empty name, though commits can carry one
+        let oid = repo
+            .odb()
+            .and_then(|odb| {
+                odb.write(
+                    git2::ObjectType::Commit,
+                    b"tree 4b825dc642cb6eb9a060e54bf8d69288fbee4904\n\
+                      author  <ghost@example.com> 0 +0000\n\
+                      committer  <ghost@example.com> 0 +0000\n\nfeat: anonymous\n",
+                )
+            })
+            .expect("Failed to write commit");
+        let commit = repo.find_commit(oid).expect("Failed to find commit");
+
+        let mut mailmap = Mailmap::new().unwrap();
+        mailmap
+            .add_entry(Some("Ghost"), None, None, "ghost@example.com")
+            .unwrap();
+        let resolver = IdentityResolver::new(Some(mailmap), &[]);
+
+        let author = resolver.resolve_signature(&commit.author());
+        assert_eq!(author.name, "Ghost");
+        assert_eq!(author.email, "ghost@example.com");
+    }
+}
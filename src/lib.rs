@@ -1,42 +1,62 @@
+pub mod authors;
+pub mod breaking;
 pub mod cache;
+pub mod codeowners;
 pub mod commit;
 pub mod config;
 pub mod convention;
 pub mod customerror;
 pub mod defaults;
+pub mod filter;
+pub mod identity;
+pub mod message;
 pub mod ownerships;
 pub mod repo;
 pub mod reporters;
+pub mod revert;
 pub mod test_utils;
 pub mod tracker;
 pub mod utils;
 pub mod window;
 
 use crate::utils::set_log_env;
-use std::{env::current_dir, fs::File, path::Path};
+use std::{env::current_dir, path::Path};
 
-use clap::Parser;
 use colored::Color;
-use reporters::{BaseReporter, Stdout};
+use customerror::Error;
+use log::warn;
+use reporters::{select_reporter, BaseReporter};
 use tracker::Tracker;
 
 use crate::{commit::CommitBucket, config::Config, repo::Repo};
 
 pub fn run() {
-    let config = Config::parse();
-
-    let config = if let Ok(config_file) = File::open(&config.config_path) {
-        match serde_json::from_reader(config_file) {
-            Ok(args) => args,
-            Err(e) => panic!("Error in configuration file:\n{}", e),
-        }
-    } else {
-        config
+    let (mut config, config_sources) = match Config::load() {
+        Ok(loaded) => loaded,
+        Err(Error::ArgError(e)) => e.exit(),
+        Err(e) => panic!("Error in configuration file:\n{}", e),
     };
+    // the logger is set up from the loaded config, so these can't go through it
+    for key in config.unknown_keys.iter() {
+        eprintln!(
+            "warning: unknown key `{}` in {}",
+            key,
+            config.config_path.display()
+        );
+    }
+
+    if config.print_config {
+        print!(
+            "{}",
+            config
+                .describe(&config_sources)
+                .expect("failed to describe the config")
+        );
+        return;
+    }
 
     set_log_env(&config.log_level);
     env_logger::init();
-    let mut perf_tracker = Tracker::new(&config, Color::Magenta, None);
 
     let path = config.path.clone().unwrap_or(
         (current_dir().expect("there should be a path"))
@@ -44,6 +64,29 @@ pub fn run() {
             .to_string(),
     );
 
+    if config.codeowners {
+        let loaded = match codeowners::load(Path::new(&path)) {
+            Ok(loaded) => loaded,
+            Err(e) => clap::Error::raw(
+                clap::error::ErrorKind::Io,
+                format!("failed to read CODEOWNERS: {}\n", e),
+            )
+            .exit(),
+        };
+        match loaded {
+            Some(codeowners) => config.merge_codeowners(codeowners),
+            None => warn!("no CODEOWNERS in {}, .github or docs", path),
+        }
+    }
+
+    if let Err(e) = config.merge_ownership_args() {
+        match e {
+            Error::ArgError(e) => e.exit(),
+            e => panic!("Error in ownership arguments:\n{}", e),
+        }
+    }
+
+    let mut perf_tracker = Tracker::new(&config, Color::Magenta, None);
     perf_tracker.start("init repo");
     let repo = Repo::init(Path::new(&path)).unwrap();
     perf_tracker.stop();
@@ -53,8 +96,13 @@ pub fn run() {
     perf_tracker.stop();
 
     perf_tracker.start("create reporter");
-    let base_reporter = BaseReporter::new(&config, &commit_bucket, Box::new(Stdout {}));
+    let base_reporter = BaseReporter::new(
+        &config,
+        &commit_bucket,
+        select_reporter(&config.output_type),
+    )
+    .expect("failed to create the reporter");
     perf_tracker.stop();
 
-    base_reporter.output();
+    base_reporter.output().expect("failed to output the report");
 }
@@ -0,0 +1,178 @@
+use regex::Regex;
+use serde::Serialize;
+
+use crate::customerror::{Error, Result};
+
+/// A `Key: value` line of the trailer block that closes a commit message, e.g., `Signed-off-by`.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct Trailer {
+    pub key: String,
+    pub value: String,
+}
+
+/// Selects commits carrying a trailer, parsed from `Key` or `Key=value`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrailerFilter {
+    pub key: String,
+    pub value: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct MessageParser {
+    trailer_regex: Regex,
+}
+
+impl MessageParser {
+    pub fn build() -> MessageParser {
+        MessageParser {
+            trailer_regex: Regex::new(
+                r"^(?P<key>[A-Za-z0-9][A-Za-z0-9-]*|BREAKING CHANGE)(?::\s*| #)(?P<value>.*)$",
+            )
+            .unwrap(),
+        }
+    }
+
+    /// Splits a commit body into the free text and the trailers of its last paragraph.
+    ///
+    /// Like `git interpret-trailers`, the last paragraph only counts as trailers when every
+    /// line is a `Key: value` pair or the indented continuation of one.
+    pub fn parse(&self, body: &str) -> (String, Vec<Trailer>) {
+        let body = body.trim();
+        let (text, last_paragraph) = match body.rsplit_once("\n\n") {
+            Some((text, last_paragraph)) => (text.trim_end(), last_paragraph),
+            None => ("", body),
+        };
+
+        let mut trailers: Vec<Trailer> = vec![];
+        for line in last_paragraph.lines() {
+            if line.starts_with([' ', '\t']) {
+                match trailers.last_mut() {
+                    Some(trailer) => {
+                        trailer.value = format!("{} {}", trailer.value, line.trim());
+                        continue;
+                    }
+                    None => return (body.to_string(), vec![]),
+                }
+            }
+
+            match self.trailer_regex.captures(line) {
+                Some(captures) => trailers.push(Trailer {
+                    key: canonical_key(&captures["key"]),
+                    value: captures["value"].trim().to_string(),
+                }),
+                None => return (body.to_string(), vec![]),
+            }
+        }
+
+        (text.to_string(), trailers)
+    }
+}
+
+/// Trailer keys are case insensitive, they are stored as `Co-authored-by`.
+pub fn canonical_key(key: &str) -> String {
+    if key.eq_ignore_ascii_case("BREAKING CHANGE") || key.eq_ignore_ascii_case("BREAKING-CHANGE") {
+        return "BREAKING CHANGE".to_string();
+    }
+
+    let mut chars = key.chars();
+    chars.next().map_or(String::new(), |first| {
+        first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect()
+    })
+}
+
+impl TrailerFilter {
+    pub fn parse(filter: &str) -> Result<TrailerFilter> {
+        let (key, value) = match filter.split_once('=') {
+            Some((key, value)) => (key.trim(), Some(value.trim().to_string())),
+            None => (filter.trim(), None),
+        };
+        if key.is_empty() {
+            return Err(Error::ParseError(format!(
+                "invalid trailer filter `{}`, expected `Key` or `Key=value`",
+                filter
+            )));
+        }
+
+        Ok(TrailerFilter {
+            key: canonical_key(key),
+            value: value.filter(|value| !value.is_empty()),
+        })
+    }
+
+    /// Matches a trailer with the same key whose value contains the filter value, ignoring case.
+    pub fn matches(&self, trailers: &[Trailer]) -> bool {
+        trailers.iter().any(|trailer| {
+            trailer.key == self.key
+                && self.value.as_ref().is_none_or(|value| {
+                    trailer.value.to_lowercase().contains(&value.to_lowercase())
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_parse_trailers() {
+        let parser = MessageParser::build();
+
+        let (body, trailers) = parser.parse(
+            "Explain the change.\n\nCo-Authored-By: Alice <alice@example.com>\nReviewed-by: Bob\n  <bob@example.com>\nRefs #12",
+        );
+        assert_eq!(body, "Explain the change.");
+        assert_eq!(
+            trailers,
+            vec![
+                Trailer {
+                    key: "Co-authored-by".to_string(),
+                    value: "Alice <alice@example.com>".to_string(),
+                },
+                Trailer {
+                    key: "Reviewed-by".to_string(),
+                    value: "Bob <bob@example.com>".to_string(),
+                },
+                Trailer {
+                    key: "Refs".to_string(),
+                    value: "12".to_string(),
+                },
+            ]
+        );
+
+        let (body, trailers) = parser.parse("Signed-off-by: Alice <alice@example.com>");
+        assert_eq!(body, "");
+        assert_eq!(trailers.len(), 1);
+
+        let (body, trailers) =
+            parser.parse("First paragraph.\n\nNote: this is prose\nnot a trailer.");
+        assert_eq!(
+            body,
+            "First paragraph.\n\nNote: this is prose\nnot a trailer."
+        );
+        assert!(trailers.is_empty());
+    }
+
+    #[test]
+    fn can_filter_trailers() {
+        let trailers = vec![Trailer {
+            key: "Reviewed-by".to_string(),
+            value: "Alice <alice@example.com>".to_string(),
+        }];
+
+        assert!(TrailerFilter::parse("reviewed-by=alice")
+            .unwrap()
+            .matches(&trailers));
+        assert!(TrailerFilter::parse("Reviewed-by")
+            .unwrap()
+            .matches(&trailers));
+        assert!(!TrailerFilter::parse("Reviewed-by=bob")
+            .unwrap()
+            .matches(&trailers));
+        assert!(!TrailerFilter::parse("Fixes").unwrap().matches(&trailers));
+        assert!(TrailerFilter::parse("=alice").is_err());
+    }
+}
@@ -1,41 +1,619 @@
-use crate::commit::{CommitBucket, CommitInfo};
-use crate::config::OwnershipConfig;
+use std::collections::HashMap;
+
+use crate::codeowners::CodeOwnersRule;
+use crate::commit::{Author, CommitBucket, CommitInfo, FileStatInfo};
+use crate::config::{Config, OwnershipConfig};
+use crate::customerror::{Error, Result};
 use regex::Regex;
+use serde::Serialize;
+
+/// Unowned files listed in the reports, the ones with the most changed lines.
+const UNOWNED_FILES: usize = 10;
+
+/// Changes to the files of a group.
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Contribution {
+    pub commits: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+/// Changes made by the `authors` of the group versus everyone else.
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamContributions {
+    pub in_team: Contribution,
+    pub out_of_team: Contribution,
+}
 
+#[derive(Clone, Debug)]
 pub struct OwnershipBucket<'a> {
+    /// Commits changing files of the group, their stats narrowed to those files.
     pub cm_bucket: CommitBucket,
     pub config: &'a OwnershipConfig,
+    /// Commits that also change files of another group.
+    pub shared_commits: usize,
+    /// None when the group lists no authors.
+    pub contributions: Option<TeamContributions>,
+}
+
+/// Paths of a group; the last matching pattern decides and a leading `!` disowns.
+#[derive(Clone, Debug)]
+pub struct OwnedPaths {
+    patterns: Vec<(Regex, bool)>,
+}
+
+/// Groups owning a path, through their own patterns or the last matching CODEOWNERS rule.
+#[derive(Clone, Debug)]
+pub struct PathOwners {
+    groups: Vec<OwnedPaths>,
+    /// Rules with the indexes of the groups they name, in the order of the file.
+    rules: Vec<(Regex, Vec<usize>)>,
+}
+
+/// Changed files no group owns.
+#[derive(Debug, Clone, Default)]
+pub struct UnownedFiles {
+    pub total: usize,
+    /// Share of the changed lines that went to unowned files.
+    pub freq: f64,
+    /// The busiest ones first.
+    pub files: Vec<FileStatInfo>,
 }
 
+/// A commit changing files owned by several groups.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossTeamCommit {
+    pub id: String,
+    pub summary: String,
+    /// Groups owning the changed files, in the order of the config.
+    pub groups: Vec<String>,
+}
+
+/// Commits of members of `author_team` changing files owned by `owning_team`.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamPair {
+    pub author_team: String,
+    pub owning_team: String,
+    pub commits: usize,
+    /// Inserted and deleted lines of the files owned by `owning_team`.
+    pub changed_lines: usize,
+    pub authors: Vec<String>,
+}
+
+/// Coupling between groups, commits spanning several of them and changes made outside a team.
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossTeamChanges {
+    pub commits: Vec<CrossTeamCommit>,
+    /// The busiest pairs first.
+    pub pairs: Vec<TeamPair>,
+}
+
+#[derive(Clone, Debug)]
 pub struct Ownerships<'a> {
     pub ow_buckets: Vec<OwnershipBucket<'a>>,
+    pub unowned: UnownedFiles,
+    pub cross_team: CrossTeamChanges,
+    path_owners: PathOwners,
+}
+
+impl OwnedPaths {
+    pub fn new(config: &OwnershipConfig) -> Result<Self> {
+        let patterns = config
+            .patterns
+            .iter()
+            .map(|pattern| {
+                let (pattern, owned) = match pattern.strip_prefix('!') {
+                    Some(pattern) => (pattern, false),
+                    None => (pattern.as_str(), true),
+                };
+                let regex = Regex::new(pattern).map_err(|e| {
+                    Error::ParseError(format!(
+                        "invalid pattern `{}` of ownership {}: {}",
+                        pattern, config.name, e
+                    ))
+                })?;
+                Ok((regex, owned))
+            })
+            .collect::<Result<Vec<(Regex, bool)>>>()?;
+
+        Ok(OwnedPaths { patterns })
+    }
+
+    pub fn owns(&self, path: &str) -> bool {
+        self.patterns
+            .iter()
+            .rev()
+            .find(|(regex, _)| regex.is_match(path))
+            .is_some_and(|(_, owned)| *owned)
+    }
+}
+
+impl PathOwners {
+    pub fn new(config: &[OwnershipConfig], rules: &[CodeOwnersRule]) -> Result<Self> {
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                let regex = Regex::new(&rule.pattern).map_err(|e| {
+                    Error::ParseError(format!(
+                        "invalid CODEOWNERS pattern `{}`: {}",
+                        rule.pattern, e
+                    ))
+                })?;
+                // owners left out by `--ownership-groups` own nothing
+                let owners = config
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, group)| rule.owners.contains(&group.name))
+                    .map(|(indx, _)| indx)
+                    .collect();
+                Ok((regex, owners))
+            })
+            .collect::<Result<Vec<(Regex, Vec<usize>)>>>()?;
+
+        Ok(PathOwners {
+            groups: config
+                .iter()
+                .map(OwnedPaths::new)
+                .collect::<Result<Vec<OwnedPaths>>>()?,
+            rules,
+        })
+    }
+
+    /// Indexes of the groups owning the path, in the order of the config.
+    pub fn owners(&self, path: &str) -> Vec<usize> {
+        let ruled = self
+            .rules
+            .iter()
+            .rev()
+            .find(|(regex, _)| regex.is_match(path))
+            .map(|(_, owners)| owners.as_slice())
+            .unwrap_or_default();
+
+        (0..self.groups.len())
+            .filter(|indx| ruled.contains(indx) || self.groups[*indx].owns(path))
+            .collect()
+    }
+}
+
+/// Owners of a path looked up in `PathOwners::owners` results, none for an unknown path.
+fn owners_of<'o>(owners: &'o HashMap<&str, Vec<usize>>, path: &str) -> &'o [usize] {
+    owners.get(path).map(Vec::as_slice).unwrap_or_default()
 }
 
 impl<'a> Ownerships<'a> {
-    pub fn build(config: &'a Vec<OwnershipConfig>, bucket: &'a CommitBucket) -> Self {
+    pub fn build(
+        config: &'a [OwnershipConfig],
+        bucket: &CommitBucket,
+        lyze_config: &Config,
+    ) -> Result<Self> {
+        let path_owners = PathOwners::new(config, &lyze_config.codeowners_rules)?;
+        // resolved once per changed path, not once per group and commit
+        let mut owners: HashMap<&str, Vec<usize>> = HashMap::new();
+        for path in bucket
+            .commits
+            .iter()
+            .filter_map(|cm| cm.stats.as_ref())
+            .flat_map(|stats| stats.file_stat_infos.iter())
+            .map(|file_stat_info| file_stat_info.path.as_str())
+            .chain(bucket.info.file_summs.keys().map(String::as_str))
+        {
+            owners
+                .entry(path)
+                .or_insert_with(|| path_owners.owners(path));
+        }
         let mut ow_buckets: Vec<OwnershipBucket> = vec![];
 
-        for conf in config {
+        for (indx, conf) in config.iter().enumerate() {
             let mut commits: Vec<CommitInfo> = vec![];
+            let mut shared_commits = 0;
             for cm in bucket.commits.iter() {
-                for pattern in conf.patterns.iter() {
-                    let rpattern = format!(r"{}", pattern);
+                let Some(stats) = &cm.stats else {
+                    continue;
+                };
+                let mut file_owners = stats
+                    .file_stat_infos
+                    .iter()
+                    .map(|file_stat_info| owners_of(&owners, &file_stat_info.path));
+                if !file_owners.clone().any(|owners| owners.contains(&indx)) {
+                    continue;
+                }
+                // a co-owned file is not a file of another group
+                if file_owners.any(|owners| !owners.contains(&indx) && !owners.is_empty()) {
+                    shared_commits += 1;
+                }
+                commits.push(cm.with_files(|file_stat_info| {
+                    owners_of(&owners, &file_stat_info.path).contains(&indx)
+                }));
+            }
 
-                    let regex = Regex::new(&rpattern).unwrap();
-                    if !regex.is_match(&cm.summary) {
-                        continue;
+            let contributions = (!conf.authors.is_empty()).then(|| {
+                let mut contributions = TeamContributions::default();
+                for cm in commits.iter() {
+                    let contribution = match cm
+                        .participants()
+                        .into_iter()
+                        .any(|author| is_member(conf, author))
+                    {
+                        true => &mut contributions.in_team,
+                        false => &mut contributions.out_of_team,
+                    };
+                    contribution.commits += 1;
+                    if let Some(stats) = &cm.stats {
+                        contribution.insertions += stats.insertions;
+                        contribution.deletions += stats.deletions;
                     }
-                    commits.push(cm.clone());
-                    break;
                 }
-            }
-            let info = CommitBucket::collect_bucket_info(&commits);
+                contributions
+            });
+
+            let info = CommitBucket::collect_bucket_info(&commits, lyze_config);
             ow_buckets.push(OwnershipBucket {
                 cm_bucket: CommitBucket { commits, info },
                 config: conf,
+                shared_commits,
+                contributions,
             })
         }
 
-        Ownerships { ow_buckets }
+        let unowned = UnownedFiles::collect(bucket, &owners);
+        let cross_team = CrossTeamChanges::collect(config, bucket, &owners);
+
+        Ok(Ownerships {
+            ow_buckets,
+            unowned,
+            cross_team,
+            path_owners,
+        })
+    }
+
+    /// Indexes of the groups owning the path, in `ow_buckets`.
+    pub fn owners(&self, path: &str) -> Vec<usize> {
+        self.path_owners.owners(path)
+    }
+}
+
+impl UnownedFiles {
+    fn collect(bucket: &CommitBucket, owners: &HashMap<&str, Vec<usize>>) -> Self {
+        let mut files = bucket
+            .info
+            .file_summs
+            .values()
+            .filter(|file| owners_of(owners, &file.path).is_empty())
+            .cloned()
+            .collect::<Vec<FileStatInfo>>();
+        files.sort_by(|a, b| {
+            b.total_changes
+                .abs()
+                .cmp(&a.total_changes.abs())
+                .then_with(|| a.path.cmp(&b.path))
+        });
+
+        let changes = |files: &mut dyn Iterator<Item = &FileStatInfo>| {
+            files.map(|file| file.total_changes.abs()).sum::<i64>()
+        };
+        let all = changes(&mut bucket.info.file_summs.values());
+        let freq = match all {
+            0 => 0.0,
+            _ => changes(&mut files.iter()) as f64 / all as f64,
+        };
+
+        UnownedFiles {
+            total: files.len(),
+            freq,
+            files: files.into_iter().take(UNOWNED_FILES).collect(),
+        }
+    }
+}
+
+impl CrossTeamChanges {
+    fn collect(
+        config: &[OwnershipConfig],
+        bucket: &CommitBucket,
+        path_owners: &HashMap<&str, Vec<usize>>,
+    ) -> Self {
+        let mut commits: Vec<CrossTeamCommit> = vec![];
+        // (author team, owning team) indexes, counted once per commit
+        let mut pairs: Vec<((usize, usize), TeamPair)> = vec![];
+
+        for cm in bucket.commits.iter() {
+            let Some(stats) = &cm.stats else {
+                continue;
+            };
+            let file_owners = stats
+                .file_stat_infos
+                .iter()
+                .map(|file| (file, owners_of(path_owners, &file.path)))
+                .collect::<Vec<_>>();
+            let owners = (0..config.len())
+                .filter(|indx| file_owners.iter().any(|(_, owners)| owners.contains(indx)))
+                .collect::<Vec<usize>>();
+            // lines of the files `owning` owns and `team` doesn't, none when they co-own them
+            let changed_lines = |team: usize, owning: usize| {
+                file_owners
+                    .iter()
+                    .filter(|(_, owners)| owners.contains(&owning) && !owners.contains(&team))
+                    .map(|(file, _)| file.inserted + file.deleted)
+                    .reduce(|sum, lines| sum + lines)
+            };
+
+            // groups co-owning every changed file they own don't make the commit cross-team
+            let spans_groups = owners.iter().any(|a| {
+                owners.iter().any(|b| {
+                    file_owners
+                        .iter()
+                        .any(|(_, owners)| owners.contains(a) != owners.contains(b))
+                })
+            });
+            if spans_groups {
+                commits.push(CrossTeamCommit {
+                    id: cm.id.clone(),
+                    summary: cm.summary.clone(),
+                    groups: owners
+                        .iter()
+                        .map(|indx| config[*indx].name.clone())
+                        .collect(),
+                });
+            }
+
+            let mut counted: Vec<(usize, usize)> = vec![];
+            for author in cm.participants() {
+                let teams = (0..config.len())
+                    .filter(|indx| is_member(&config[*indx], author))
+                    .collect::<Vec<usize>>();
+                for owning in owners.iter().filter(|owning| !teams.contains(owning)) {
+                    for team in teams.iter() {
+                        let Some(lines) = changed_lines(*team, *owning) else {
+                            continue;
+                        };
+                        let key = (*team, *owning);
+                        let pair = match pairs.iter().position(|(k, _)| *k == key) {
+                            Some(indx) => &mut pairs[indx].1,
+                            None => {
+                                pairs.push((
+                                    key,
+                                    TeamPair {
+                                        author_team: config[*team].name.clone(),
+                                        owning_team: config[*owning].name.clone(),
+                                        commits: 0,
+                                        changed_lines: 0,
+                                        authors: vec![],
+                                    },
+                                ));
+                                &mut pairs.last_mut().unwrap().1
+                            }
+                        };
+                        if !pair.authors.contains(&author.name) {
+                            pair.authors.push(author.name.clone());
+                        }
+                        if !counted.contains(&key) {
+                            counted.push(key);
+                            pair.commits += 1;
+                            pair.changed_lines += lines;
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut pairs = pairs.into_iter().map(|(_, pair)| pair).collect::<Vec<_>>();
+        pairs.sort_by(|a, b| {
+            b.commits
+                .cmp(&a.commits)
+                .then_with(|| a.author_team.cmp(&b.author_team))
+                .then_with(|| a.owning_team.cmp(&b.owning_team))
+        });
+
+        CrossTeamChanges { commits, pairs }
+    }
+}
+
+/// Whether the name or the email of the author is listed in the group's `authors`.
+pub fn is_member(config: &OwnershipConfig, author: &Author) -> bool {
+    config.authors.iter().any(|member| {
+        member.eq_ignore_ascii_case(&author.name)
+            || (!author.email.is_empty() && member.eq_ignore_ascii_case(&author.email))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commit::{FileStatInfo, Stats};
+    use crate::test_utils::mock_config;
+
+    fn ownership(name: &str, patterns: &[&str], authors: &[&str]) -> OwnershipConfig {
+        OwnershipConfig {
+            name: name.to_string(),
+            patterns: patterns.iter().map(|p| p.to_string()).collect(),
+            authors: authors.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    fn commit(author: &str, paths: &[&str]) -> CommitInfo {
+        CommitInfo {
+            author: Author {
+                name: author.to_string(),
+                email: format!("{}@example.com", author),
+            },
+            stats: Some(Stats {
+                file_stat_infos: paths
+                    .iter()
+                    .map(|path| FileStatInfo {
+                        path: path.to_string(),
+                        inserted: 2,
+                        deleted: 1,
+                        total_changes: 3,
+                    })
+                    .collect(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn can_collect_cross_team_changes() {
+        let config = vec![
+            ownership("api", &["^api/"], &["alice"]),
+            ownership("web", &["^web/"], &["bob"]),
+            ownership("docs", &["^docs/"], &[]),
+        ];
+        let mut co_authored = commit("bob", &["api/a.rs", "docs/b.md"]);
+        co_authored.co_authors = vec![Author::parse("Alice <alice@example.com>")];
+        let commits = vec![
+            commit("alice", &["api/a.rs", "web/index.ts"]),
+            commit("bob", &["api/a.rs", "api/b.rs"]),
+            co_authored,
+            commit("carol", &["web/index.ts", "docs/a.md"]),
+            commit("alice", &["api/c.rs"]),
+        ];
+        let lyze_config = mock_config(None);
+        let info = CommitBucket::collect_bucket_info(&commits, &lyze_config);
+        let bucket = CommitBucket { commits, info };
+
+        let cross_team = Ownerships::build(&config, &bucket, &lyze_config)
+            .unwrap()
+            .cross_team;
+        let groups = cross_team
+            .commits
+            .iter()
+            .map(|cm| cm.groups.join(" "))
+            .collect::<Vec<String>>();
+        assert_eq!(groups, vec!["api web", "api docs", "web docs"]);
+
+        let pairs = cross_team
+            .pairs
+            .iter()
+            .map(|pair| {
+                (
+                    pair.author_team.as_str(),
+                    pair.owning_team.as_str(),
+                    pair.commits,
+                    pair.changed_lines,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pairs,
+            vec![
+                ("web", "api", 2, 9),
+                ("api", "docs", 1, 3),
+                ("api", "web", 1, 3),
+                ("web", "docs", 1, 3),
+            ]
+        );
+        assert_eq!(cross_team.pairs[0].authors, vec!["bob"]);
+    }
+
+    #[test]
+    fn can_ignore_co_owned_files() {
+        let codeowners = crate::codeowners::parse("api/ @alice @bob\nweb/ @carol\n").unwrap();
+        let mut lyze_config = mock_config(None);
+        lyze_config.codeowners_rules = codeowners.rules;
+        let commits = vec![
+            commit("alice", &["api/a.rs"]),
+            commit("bob", &["api/a.rs", "web/b.ts"]),
+            commit("carol", &["api/a.rs"]),
+        ];
+        let info = CommitBucket::collect_bucket_info(&commits, &lyze_config);
+        let bucket = CommitBucket { commits, info };
+
+        let ownerships = Ownerships::build(&codeowners.groups, &bucket, &lyze_config).unwrap();
+        assert_eq!(ownerships.ow_buckets[0].cm_bucket.info.total, 3);
+        assert_eq!(ownerships.ow_buckets[0].shared_commits, 1);
+
+        let cross_team = ownerships.cross_team;
+        assert_eq!(cross_team.commits.len(), 1);
+        assert_eq!(
+            cross_team.commits[0].groups,
+            vec!["@alice", "@bob", "@carol"]
+        );
+        let pairs = cross_team
+            .pairs
+            .iter()
+            .map(|pair| {
+                (
+                    pair.author_team.as_str(),
+                    pair.owning_team.as_str(),
+                    pair.commits,
+                    pair.changed_lines,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pairs,
+            vec![
+                ("@bob", "@carol", 1, 3),
+                ("@carol", "@alice", 1, 3),
+                ("@carol", "@bob", 1, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn can_match_owned_paths() {
+        let owned = OwnedPaths::new(&ownership("web", &["^web/", "!^web/vendor/"], &[])).unwrap();
+        assert!(owned.owns("web/index.ts"));
+        assert!(!owned.owns("web/vendor/lib.js"));
+        assert!(!owned.owns("api/web/main.rs"));
+        assert!(OwnedPaths::new(&ownership("web", &["^web/", "!("], &[])).is_err());
+    }
+
+    #[test]
+    fn can_build_path_ownerships() {
+        let config = vec![
+            ownership("api", &["^api/"], &["alice"]),
+            ownership("web", &["^web/"], &[]),
+        ];
+        let commits = vec![
+            commit("alice", &["api/a.rs", "api/b.rs"]),
+            commit("bob", &["api/a.rs", "web/index.ts", "README.md"]),
+            commit("bob", &["web/index.ts"]),
+            commit("carol", &["README.md"]),
+        ];
+        let lyze_config = mock_config(None);
+        let info = CommitBucket::collect_bucket_info(&commits, &lyze_config);
+        let bucket = CommitBucket { commits, info };
+
+        let ownerships = Ownerships::build(&config, &bucket, &lyze_config).unwrap();
+        let api = &ownerships.ow_buckets[0];
+        assert_eq!(api.cm_bucket.info.total, 2);
+        assert_eq!(api.shared_commits, 1);
+        assert_eq!(api.cm_bucket.info.file_summs.len(), 2);
+        assert_eq!(api.cm_bucket.info.file_summs["api/a.rs"].total_changes, 6);
+        assert_eq!(
+            api.contributions,
+            Some(TeamContributions {
+                in_team: Contribution {
+                    commits: 1,
+                    insertions: 4,
+                    deletions: 2,
+                },
+                out_of_team: Contribution {
+                    commits: 1,
+                    insertions: 2,
+                    deletions: 1,
+                },
+            })
+        );
+
+        let web = &ownerships.ow_buckets[1];
+        assert_eq!(web.cm_bucket.info.total, 2);
+        assert_eq!(
+            web.cm_bucket.commits[0].stats.as_ref().unwrap().insertions,
+            2
+        );
+        assert!(web.contributions.is_none());
+
+        assert_eq!(ownerships.owners("web/index.ts"), vec![1]);
+        assert!(ownerships.owners("README.md").is_empty());
+        assert_eq!(ownerships.unowned.total, 1);
+        assert_eq!(ownerships.unowned.files[0].path, "README.md");
+        assert_eq!(ownerships.unowned.freq, 6.0 / 21.0);
     }
 }
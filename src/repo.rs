@@ -1,5 +1,6 @@
+use crate::config::{CommitSortType, Config};
 use crate::customerror::{Error, Result};
-use git2::{Commit, Diff, DiffOptions, Repository, Sort};
+use git2::{Commit, Diff, DiffOptions, Mailmap, Oid, Repository, Sort};
 use std::io;
 use std::path::Path;
 
@@ -7,6 +8,48 @@ pub struct Repo {
     dot_git: Repository,
 }
 
+/// Which commits the revwalk starts from and which ones it hides, HEAD when empty.
+#[derive(Debug, Default, Clone)]
+pub struct RevisionSelection {
+    /// `A..B` ranges
+    pub ranges: Vec<String>,
+    /// revisions to walk from, a leading `^` hides the revision instead
+    pub revs: Vec<String>,
+    /// revisions whose history is excluded
+    pub hide: Vec<String>,
+    /// walk every local branch and tag
+    pub all: bool,
+}
+
+impl From<&Config> for RevisionSelection {
+    fn from(config: &Config) -> Self {
+        RevisionSelection {
+            ranges: config.range.iter().cloned().collect(),
+            revs: config.revs.clone().unwrap_or_default(),
+            hide: config.since_ref.iter().cloned().collect(),
+            all: config.all,
+        }
+    }
+}
+
+impl RevisionSelection {
+    /// Revisions as they would be passed to `git log`, e.g., `v1..v2 ^main`.
+    pub fn describe(&self) -> String {
+        let revisions = self
+            .all
+            .then(|| "--all".to_string())
+            .into_iter()
+            .chain(self.ranges.iter().cloned())
+            .chain(self.revs.iter().cloned())
+            .chain(self.hide.iter().map(|hide| format!("^{}", hide)))
+            .collect::<Vec<String>>();
+        match revisions.is_empty() {
+            true => "HEAD".to_string(),
+            false => revisions.join(" "),
+        }
+    }
+}
+
 impl Repo {
     pub fn init(path: &Path) -> Result<Self> {
         if path.exists() {
@@ -21,21 +64,94 @@ impl Repo {
         }
     }
 
-    pub fn get_commits(&self) -> Result<Vec<Commit>> {
+    /// Another handle on the same repository, git2 handles can't be shared between threads.
+    pub fn reopen(&self) -> Result<Self> {
+        Ok(Self {
+            dot_git: Repository::open(self.dot_git.path())?,
+        })
+    }
+
+    /// The `.mailmap` of the work tree, merged with `mailmap.file` and `mailmap.blob`.
+    pub fn mailmap(&self) -> Result<Mailmap> {
+        Ok(self.dot_git.mailmap()?)
+    }
+
+    pub fn get_commits(
+        &self,
+        selection: &RevisionSelection,
+        order: &[CommitSortType],
+    ) -> Result<Vec<Commit<'_>>> {
         let mut walk = self.dot_git.revwalk()?;
-        walk.set_sorting(Sort::TOPOLOGICAL)?;
+        let reverse = order.contains(&CommitSortType::Reverse);
+        let sorting = order
+            .iter()
+            .fold(Sort::NONE, |sorting, sort_type| match sort_type {
+                CommitSortType::Topological => sorting | Sort::TOPOLOGICAL,
+                CommitSortType::Time => sorting | Sort::TIME,
+                CommitSortType::Reverse => sorting | Sort::REVERSE,
+                CommitSortType::AuthorTime => sorting,
+            });
+        walk.set_sorting(sorting)?;
 
-        walk.push_head()?;
+        let mut pushed = false;
+        if selection.all {
+            walk.push_glob("refs/heads/*")?;
+            walk.push_glob("refs/tags/*")?;
+            pushed = true;
+        }
+
+        for range in selection.ranges.iter() {
+            walk.push_range(range)?;
+            pushed = true;
+        }
 
-        let commits: Vec<Commit> = walk
+        for rev in selection.revs.iter() {
+            match rev.strip_prefix('^') {
+                Some(hidden) => walk.hide(self.resolve_commit(hidden)?)?,
+                None => {
+                    walk.push(self.resolve_commit(rev)?)?;
+                    pushed = true;
+                }
+            }
+        }
+
+        for hidden in selection.hide.iter() {
+            walk.hide(self.resolve_commit(hidden)?)?;
+        }
+
+        if !pushed {
+            walk.push_head()?;
+        }
+
+        let mut commits: Vec<Commit> = walk
             .filter_map(|oid| oid.ok())
             .filter_map(|oid| self.dot_git.find_commit(oid).ok())
             .collect();
 
+        // revwalk only knows the committer time, author time is sorted afterwards
+        if order.contains(&CommitSortType::AuthorTime) {
+            commits.sort_by(|a, b| {
+                let (a_time, b_time) = (a.author().when().seconds(), b.author().when().seconds());
+                if reverse {
+                    a_time.cmp(&b_time)
+                } else {
+                    b_time.cmp(&a_time)
+                }
+            });
+        }
+
         Ok(commits)
     }
 
-    pub fn find_last_commit(&self) -> Result<Commit> {
+    pub fn find_commit(&self, oid: Oid) -> Result<Commit<'_>> {
+        Ok(self.dot_git.find_commit(oid)?)
+    }
+
+    fn resolve_commit(&self, rev: &str) -> Result<Oid> {
+        Ok(self.dot_git.revparse_single(rev)?.peel_to_commit()?.id())
+    }
+
+    pub fn find_last_commit(&self) -> Result<Commit<'_>> {
         let obj = self
             .dot_git
             .head()?
@@ -45,8 +161,11 @@ impl Repo {
             .map_err(|_| Error::GitError(git2::Error::from_str("Couldn't find the commit")))
     }
 
-    // TODO: increase perf
-    pub fn get_diff(&self, commit: &git2::Commit, opts: Option<&mut DiffOptions>) -> Option<Diff> {
+    pub fn get_diff(
+        &self,
+        commit: &git2::Commit,
+        opts: Option<&mut DiffOptions>,
+    ) -> Option<Diff<'_>> {
         if let Ok(prev_commit) = commit.parent(0) {
             let diff = self.dot_git.diff_tree_to_tree(
                 prev_commit.tree().ok().as_ref(),
@@ -68,6 +187,7 @@ mod tests {
     use std::path::PathBuf;
 
     use super::*;
+    use crate::test_utils::setup_repo;
     use std::cell::RefCell;
     use tempfile::TempDir;
 
@@ -128,8 +248,165 @@ mod tests {
             )
             .unwrap();
 
-        let commits = b_repo.get_commits();
+        let commits = b_repo.get_commits(
+            &RevisionSelection::default(),
+            &[CommitSortType::Topological],
+        );
         assert!(commits.is_ok());
         assert_eq!(commits.unwrap().len(), 2);
     }
+
+    fn summaries(repo: &Repo, selection: RevisionSelection) -> Vec<String> {
+        sorted_summaries(repo, selection, &[CommitSortType::Topological])
+    }
+
+    fn sorted_summaries(
+        repo: &Repo,
+        selection: RevisionSelection,
+        order: &[CommitSortType],
+    ) -> Vec<String> {
+        repo.get_commits(&selection, order)
+            .expect("Failed to get commits")
+            .iter()
+            .map(|commit| commit.summary().unwrap_or("").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn can_select_revisions() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo, _) = setup_repo(&temp_dir);
+        let tagged = repo.resolve_commit("HEAD~2").unwrap();
+        repo.dot_git
+            .tag_lightweight(
+                "v1",
+                &repo.dot_git.find_object(tagged, None).unwrap(),
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(
+            summaries(
+                &repo,
+                RevisionSelection {
+                    hide: vec!["v1".to_string()],
+                    ..Default::default()
+                }
+            ),
+            vec!["feat(repo): idk", "feat(main): test"]
+        );
+        assert_eq!(
+            summaries(
+                &repo,
+                RevisionSelection {
+                    ranges: vec!["HEAD~4..v1".to_string()],
+                    ..Default::default()
+                }
+            ),
+            vec!["test(main): idk", "test(commit): base tests"]
+        );
+        assert_eq!(
+            summaries(
+                &repo,
+                RevisionSelection {
+                    revs: vec!["v1".to_string(), "^HEAD~4".to_string()],
+                    ..Default::default()
+                }
+            )
+            .len(),
+            2
+        );
+        assert_eq!(
+            summaries(
+                &repo,
+                RevisionSelection {
+                    all: true,
+                    ..Default::default()
+                }
+            )
+            .len(),
+            5
+        );
+
+        let selection = RevisionSelection {
+            ranges: vec!["HEAD~4..v1".to_string()],
+            hide: vec!["main".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(selection.describe(), "HEAD~4..v1 ^main");
+        assert_eq!(RevisionSelection::default().describe(), "HEAD");
+
+        let selection = RevisionSelection {
+            revs: vec!["^v1".to_string()],
+            hide: vec!["main".to_string()],
+            all: true,
+            ..Default::default()
+        };
+        assert_eq!(selection.describe(), "--all ^v1 ^main");
+    }
+
+    #[test]
+    fn can_sort_commits() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let git_repo = Repository::init(temp_dir.path()).expect("Failed to create repository");
+        let tree = git_repo
+            .find_tree(git_repo.index().unwrap().write_tree().unwrap())
+            .unwrap();
+        let committer = git2::Signature::new("Eren", "e@e.e", &git2::Time::new(1000, 0)).unwrap();
+
+        let mut parents: Vec<Oid> = vec![];
+        for (message, author_time) in [("first", 300), ("second", 100), ("third", 200)] {
+            let author =
+                git2::Signature::new("Eren", "e@e.e", &git2::Time::new(author_time, 0)).unwrap();
+            let parent_commits = parents
+                .iter()
+                .map(|oid| git_repo.find_commit(*oid).unwrap())
+                .collect::<Vec<_>>();
+            let oid = git_repo
+                .commit(
+                    Some("HEAD"),
+                    &author,
+                    &committer,
+                    message,
+                    &tree,
+                    &parent_commits.iter().collect::<Vec<_>>(),
+                )
+                .unwrap();
+            parents = vec![oid];
+        }
+        let repo = Repo::init(temp_dir.path()).unwrap();
+
+        assert_eq!(
+            sorted_summaries(
+                &repo,
+                RevisionSelection::default(),
+                &[CommitSortType::Topological]
+            ),
+            vec!["third", "second", "first"]
+        );
+        assert_eq!(
+            sorted_summaries(
+                &repo,
+                RevisionSelection::default(),
+                &[CommitSortType::Topological, CommitSortType::Reverse]
+            ),
+            vec!["first", "second", "third"]
+        );
+        assert_eq!(
+            sorted_summaries(
+                &repo,
+                RevisionSelection::default(),
+                &[CommitSortType::AuthorTime]
+            ),
+            vec!["first", "third", "second"]
+        );
+        assert_eq!(
+            sorted_summaries(
+                &repo,
+                RevisionSelection::default(),
+                &[CommitSortType::AuthorTime, CommitSortType::Reverse]
+            ),
+            vec!["second", "third", "first"]
+        );
+    }
 }
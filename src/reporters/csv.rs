@@ -0,0 +1,299 @@
+use std::io::Write;
+
+use chrono::DateTime;
+
+use crate::{
+    authors::TopEntry,
+    commit::CommitBucket,
+    config::{Config, CsvTable},
+    customerror::Result,
+    ownerships::Ownerships,
+    utils::map_file_summs,
+};
+
+use super::{open_output, Reporter};
+
+/// Delimiter separated reporter, `,` for csv and `\t` for tsv.
+pub struct Csv {
+    pub delimiter: char,
+}
+
+impl<'a> Reporter<'a> for Csv {
+    fn output(
+        &self,
+        config: &Config,
+        report_info: &CommitBucket,
+        _ownerships_info: &Option<Ownerships<'a>>,
+    ) -> Result<()> {
+        let mut out = open_output(config)?;
+        self.write_tables(&mut out, config, report_info)?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+impl Csv {
+    pub fn write_tables(
+        &self,
+        out: &mut dyn Write,
+        config: &Config,
+        bucket: &CommitBucket,
+    ) -> Result<()> {
+        for (indx, table) in config.csv_tables.iter().enumerate() {
+            if indx > 0 {
+                writeln!(out)?;
+            }
+            match table {
+                CsvTable::Commits => self.write_commits(out, bucket)?,
+                CsvTable::Files => self.write_files(out, config, bucket)?,
+                CsvTable::CommitFiles => self.write_commit_files(out, bucket)?,
+                CsvTable::Authors => self.write_authors(out, bucket)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_commits(&self, out: &mut dyn Write, bucket: &CommitBucket) -> Result<()> {
+        self.write_record(
+            out,
+            &[
+                "hash",
+                "author_name",
+                "author_email",
+                "time",
+                "type",
+                "scope",
+                "summary",
+                "insertions",
+                "deletions",
+                "changed_files_count",
+                "reverts",
+                "breaking",
+                "trailers",
+            ],
+        )?;
+
+        for commit in bucket.commits.iter() {
+            let (insertions, deletions, changed_files_count) =
+                commit.stats.as_ref().map_or((0, 0, 0), |stats| {
+                    (stats.insertions, stats.deletions, stats.changed_files_count)
+                });
+
+            self.write_record(
+                out,
+                &[
+                    &commit.id,
+                    &commit.author.name,
+                    &commit.author.email,
+                    &format_time(commit.time),
+                    &commit.type_,
+                    &commit.scope,
+                    &commit.summary,
+                    &insertions.to_string(),
+                    &deletions.to_string(),
+                    &changed_files_count.to_string(),
+                    commit
+                        .revert_of
+                        .as_ref()
+                        .and_then(|revert_of| revert_of.id.as_deref())
+                        .unwrap_or(""),
+                    &commit.breaking.to_string(),
+                    &commit
+                        .trailers
+                        .iter()
+                        .map(|trailer| format!("{}: {}", trailer.key, trailer.value))
+                        .collect::<Vec<String>>()
+                        .join("; "),
+                ],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn write_files(
+        &self,
+        out: &mut dyn Write,
+        config: &Config,
+        bucket: &CommitBucket,
+    ) -> Result<()> {
+        self.write_record(out, &["path", "inserted", "deleted", "total_changes"])?;
+
+        for file_summ in map_file_summs(config, &bucket.info.file_summs) {
+            self.write_record(
+                out,
+                &[
+                    &file_summ.path,
+                    &file_summ.inserted.to_string(),
+                    &file_summ.deleted.to_string(),
+                    &file_summ.total_changes.to_string(),
+                ],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn write_commit_files(&self, out: &mut dyn Write, bucket: &CommitBucket) -> Result<()> {
+        self.write_record(
+            out,
+            &["hash", "path", "inserted", "deleted", "total_changes"],
+        )?;
+
+        for commit in bucket.commits.iter() {
+            let Some(stats) = &commit.stats else {
+                continue;
+            };
+            for file_stat_info in stats.file_stat_infos.iter() {
+                self.write_record(
+                    out,
+                    &[
+                        &commit.id,
+                        &file_stat_info.path,
+                        &file_stat_info.inserted.to_string(),
+                        &file_stat_info.deleted.to_string(),
+                        &file_stat_info.total_changes.to_string(),
+                    ],
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_authors(&self, out: &mut dyn Write, bucket: &CommitBucket) -> Result<()> {
+        self.write_record(
+            out,
+            &[
+                "author_name",
+                "author_email",
+                "commits",
+                "freq",
+                "co_authored",
+                "insertions",
+                "deletions",
+                "files_touched",
+                "active_days",
+                "first_commit",
+                "last_commit",
+                "top_types",
+                "top_scopes",
+                "top_files",
+            ],
+        )?;
+
+        for author in bucket.info.authors.iter() {
+            self.write_record(
+                out,
+                &[
+                    &author.name,
+                    &author.email,
+                    &author.commits.to_string(),
+                    &author.freq.to_string(),
+                    &author.co_authored.to_string(),
+                    &author.insertions.to_string(),
+                    &author.deletions.to_string(),
+                    &author.files_touched.to_string(),
+                    &author.active_days.to_string(),
+                    &format_time(author.first_commit),
+                    &format_time(author.last_commit),
+                    &TopEntry::join(&author.top_types),
+                    &TopEntry::join(&author.top_scopes),
+                    &TopEntry::join(&author.top_files),
+                ],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn write_record(&self, out: &mut dyn Write, fields: &[&str]) -> Result<()> {
+        let record = fields
+            .iter()
+            .map(|field| self.quote(field))
+            .collect::<Vec<_>>()
+            .join(&self.delimiter.to_string());
+        writeln!(out, "{}", record)?;
+        Ok(())
+    }
+
+    /// Quotes the field when it contains the delimiter, a quote or a line break.
+    pub fn quote(&self, field: &str) -> String {
+        if field.contains([self.delimiter, '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+}
+
+fn format_time(seconds: i64) -> String {
+    DateTime::from_timestamp(seconds, 0).map_or(String::new(), |time| time.to_rfc3339())
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::test_utils::{commit_files, mock_repo_config, setup_repo};
+
+    #[test]
+    fn can_quote_fields() {
+        let csv = Csv { delimiter: ',' };
+        assert_eq!(csv.quote("src/main.rs"), "src/main.rs");
+        assert_eq!(csv.quote("a,b"), "\"a,b\"");
+        assert_eq!(csv.quote("say \"hi\""), "\"say \"\"hi\"\"\"");
+
+        let tsv = Csv { delimiter: '\t' };
+        assert_eq!(tsv.quote("a,b"), "a,b");
+        assert_eq!(tsv.quote("a\tb"), "\"a\tb\"");
+    }
+
+    #[test]
+    fn can_write_selected_tables() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo, example_commit_message) = setup_repo(&temp_dir);
+        commit_files(
+            &temp_dir,
+            &[
+                ("feat(csv): add lib", &[("src/lib.rs", "a\nb\nc\n")]),
+                (
+                    "fix(csv): trim lib",
+                    &[("src/lib.rs", "a\n"), ("a,b.txt", "x\n")],
+                ),
+            ],
+        );
+        let config = mock_repo_config(
+            &temp_dir,
+            Some(vec!["", "--csv-tables", "commits", "files"]),
+        );
+        let bucket = CommitBucket::build(&repo, example_commit_message.as_str(), &config)
+            .expect("Failed to build commit bucket");
+
+        let mut out: Vec<u8> = vec![];
+        Csv { delimiter: ',' }
+            .write_tables(&mut out, &config, &bucket)
+            .expect("Failed to write tables");
+        let out = String::from_utf8(out).unwrap();
+        let mut tables = out.split("\n\n");
+
+        let commits = tables.next().unwrap().lines().collect::<Vec<_>>();
+        assert_eq!(commits.len(), 8);
+        assert!(commits[0].starts_with("hash,author_name,author_email,time,type,scope"));
+        assert!(commits[1].contains(",erencam,erencam.dev@gmail.com,"));
+        assert!(commits[1].contains(",fix,csv,fix(csv): trim lib,"));
+        assert!(commits[3].contains(",feat,repo,feat(repo): idk,"));
+
+        let files = tables.next().unwrap().lines().collect::<Vec<_>>();
+        assert_eq!(
+            files,
+            vec![
+                "path,inserted,deleted,total_changes",
+                "src/lib.rs,3,2,5",
+                "\"a,b.txt\",1,0,1",
+            ]
+        );
+    }
+}
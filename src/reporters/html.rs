@@ -0,0 +1,53 @@
+use std::io::Write;
+
+use crate::{commit::CommitBucket, config::Config, customerror::Result, ownerships::Ownerships};
+
+use super::{json::JsonReport, open_output, Reporter};
+
+const TEMPLATE: &str = include_str!("dashboard.html");
+const DATA_PLACEHOLDER: &str = "{{DATA}}";
+
+/// Self contained html dashboard, the json report is embedded and rendered by inline scripts.
+pub struct Html {}
+
+impl<'a> Reporter<'a> for Html {
+    fn output(
+        &self,
+        config: &Config,
+        report_info: &CommitBucket,
+        ownerships_info: &Option<Ownerships<'a>>,
+    ) -> Result<()> {
+        let report = JsonReport::build(config, report_info, ownerships_info);
+        let page = render_html(&serde_json::to_string(&report)?);
+
+        let mut out = open_output(config)?;
+        out.write_all(page.as_bytes())?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+pub fn render_html(report_json: &str) -> String {
+    // `</` or `<!--` would end the surrounding script tag early, these characters only occur
+    // in json strings, where a unicode escape reads the same.
+    let report_json = report_json
+        .replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026");
+    TEMPLATE.replace(DATA_PLACEHOLDER, &report_json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_embed_report_data() {
+        let page = render_html(r#"{"summary":"</script><!-- &amp;"}"#);
+
+        assert!(!page.contains(DATA_PLACEHOLDER));
+        assert!(page.contains(r#"{"summary":"\u003c/script\u003e\u003c!-- \u0026amp;"}"#));
+        assert!(!page.contains("</script><!--"));
+        assert!(!page.contains("src=\"http"));
+    }
+}
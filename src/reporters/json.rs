@@ -0,0 +1,285 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::{
+    authors::AuthorStats,
+    breaking::BreakingSummary,
+    commit::{Author, CommitBucket, CommitInfo, FileStatInfo, Freq},
+    config::Config,
+    customerror::Result,
+    message::Trailer,
+    ownerships::{CrossTeamChanges, Ownerships, TeamContributions},
+    revert::{RevertRef, RevertSummary},
+    utils::map_file_summs,
+};
+
+use super::{open_output, Reporter};
+
+/// Bumped whenever a field of the json document is renamed or removed.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+pub struct Json {}
+
+impl<'a> Reporter<'a> for Json {
+    fn output(
+        &self,
+        config: &Config,
+        report_info: &CommitBucket,
+        ownerships_info: &Option<Ownerships<'a>>,
+    ) -> Result<()> {
+        let report = JsonReport::build(config, report_info, ownerships_info);
+
+        let mut out = open_output(config)?;
+        serde_json::to_writer_pretty(&mut out, &report)?;
+        writeln!(out)?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonReport<'a> {
+    pub version: u32,
+    pub total: usize,
+    pub commits: Vec<JsonCommit<'a>>,
+    pub types: JsonFreq<'a>,
+    pub scopes: JsonFreq<'a>,
+    pub trailers: JsonFreq<'a>,
+    pub files: Vec<JsonFile<'a>>,
+    pub reverts: &'a RevertSummary,
+    pub breaking: &'a BreakingSummary,
+    pub authors: &'a [AuthorStats],
+    pub ownerships: Option<Vec<JsonOwnership<'a>>>,
+    pub unowned_files: Option<JsonUnownedFiles<'a>>,
+    pub cross_team: Option<&'a CrossTeamChanges>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonCommit<'a> {
+    pub id: &'a str,
+    pub author: &'a Author,
+    pub co_authors: &'a [Author],
+    pub summary: &'a str,
+    pub body: &'a str,
+    pub trailers: &'a [Trailer],
+    #[serde(rename = "type")]
+    pub type_: &'a str,
+    pub scope: &'a str,
+    pub time: i64,
+    pub insertions: usize,
+    pub deletions: usize,
+    pub changed_files_count: usize,
+    pub files: Vec<JsonFile<'a>>,
+    pub revert_of: Option<&'a RevertRef>,
+    pub breaking: bool,
+    pub breaking_description: Option<&'a str>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonFreq<'a> {
+    pub total: u32,
+    pub info: BTreeMap<&'a str, JsonFreqInfo>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonFreqInfo {
+    pub count: u32,
+    pub freq: f64,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonFile<'a> {
+    pub path: &'a str,
+    pub inserted: usize,
+    pub deleted: usize,
+    pub total_changes: i64,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonOwnership<'a> {
+    pub name: &'a str,
+    pub patterns: &'a [String],
+    pub authors: &'a [String],
+    pub total: usize,
+    pub shared_commits: usize,
+    pub contributions: &'a Option<TeamContributions>,
+    pub types: JsonFreq<'a>,
+    pub scopes: JsonFreq<'a>,
+    pub files: Vec<JsonFile<'a>>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonUnownedFiles<'a> {
+    pub total: usize,
+    pub freq: f64,
+    pub files: Vec<JsonFile<'a>>,
+}
+
+impl<'a> JsonReport<'a> {
+    pub fn build(
+        config: &Config,
+        bucket: &'a CommitBucket,
+        ownerships_info: &'a Option<Ownerships>,
+    ) -> Self {
+        let ownerships = ownerships_info.as_ref().map(|ownerships| {
+            ownerships
+                .ow_buckets
+                .iter()
+                .map(|ow_bucket| JsonOwnership {
+                    name: &ow_bucket.config.name,
+                    patterns: &ow_bucket.config.patterns,
+                    authors: &ow_bucket.config.authors,
+                    total: ow_bucket.cm_bucket.info.total,
+                    shared_commits: ow_bucket.shared_commits,
+                    contributions: &ow_bucket.contributions,
+                    types: JsonFreq::from(&ow_bucket.cm_bucket.info.types),
+                    scopes: JsonFreq::from(&ow_bucket.cm_bucket.info.scopes),
+                    files: map_file_summs(config, &ow_bucket.cm_bucket.info.file_summs)
+                        .into_iter()
+                        .map(JsonFile::from)
+                        .collect(),
+                })
+                .collect()
+        });
+
+        let unowned_files = ownerships_info.as_ref().map(|ownerships| JsonUnownedFiles {
+            total: ownerships.unowned.total,
+            freq: ownerships.unowned.freq,
+            files: ownerships
+                .unowned
+                .files
+                .iter()
+                .map(JsonFile::from)
+                .collect(),
+        });
+
+        JsonReport {
+            version: JSON_SCHEMA_VERSION,
+            total: bucket.info.total,
+            commits: bucket.commits.iter().map(JsonCommit::from).collect(),
+            types: JsonFreq::from(&bucket.info.types),
+            scopes: JsonFreq::from(&bucket.info.scopes),
+            trailers: JsonFreq::from(&bucket.info.trailers),
+            files: map_file_summs(config, &bucket.info.file_summs)
+                .into_iter()
+                .map(JsonFile::from)
+                .collect(),
+            reverts: &bucket.info.reverts,
+            breaking: &bucket.info.breaking,
+            authors: &bucket.info.authors,
+            ownerships,
+            unowned_files,
+            cross_team: ownerships_info
+                .as_ref()
+                .map(|ownerships| &ownerships.cross_team),
+        }
+    }
+}
+
+impl<'a> From<&'a CommitInfo> for JsonCommit<'a> {
+    fn from(commit: &'a CommitInfo) -> Self {
+        let (insertions, deletions, changed_files_count, files) = match &commit.stats {
+            Some(stats) => (
+                stats.insertions,
+                stats.deletions,
+                stats.changed_files_count,
+                stats.file_stat_infos.iter().map(JsonFile::from).collect(),
+            ),
+            None => (0, 0, 0, vec![]),
+        };
+
+        JsonCommit {
+            id: &commit.id,
+            author: &commit.author,
+            co_authors: &commit.co_authors,
+            summary: &commit.summary,
+            body: &commit.body,
+            trailers: &commit.trailers,
+            type_: &commit.type_,
+            scope: &commit.scope,
+            time: commit.time,
+            insertions,
+            deletions,
+            changed_files_count,
+            files,
+            revert_of: commit.revert_of.as_ref(),
+            breaking: commit.breaking,
+            breaking_description: commit.breaking_description.as_deref(),
+        }
+    }
+}
+
+impl<'a> From<&'a Freq> for JsonFreq<'a> {
+    fn from(freq: &'a Freq) -> Self {
+        JsonFreq {
+            total: freq.total,
+            info: freq
+                .info
+                .iter()
+                .map(|(key, info)| {
+                    (
+                        key.as_str(),
+                        JsonFreqInfo {
+                            count: info.count,
+                            freq: info.freq,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<'a> From<&'a FileStatInfo> for JsonFile<'a> {
+    fn from(file: &'a FileStatInfo) -> Self {
+        JsonFile {
+            path: &file.path,
+            inserted: file.inserted,
+            deleted: file.deleted,
+            total_changes: file.total_changes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::test_utils::{mock_repo_config, setup_repo};
+
+    #[test]
+    fn can_build_json_report() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo, example_commit_message) = setup_repo(&temp_dir);
+        let config = mock_repo_config(&temp_dir, None);
+        let bucket = CommitBucket::build(&repo, example_commit_message.as_str(), &config)
+            .expect("Failed to build commit bucket");
+
+        let report = JsonReport::build(&config, &bucket, &None);
+        let value = serde_json::to_value(&report).expect("Failed to serialize report");
+
+        assert_eq!(value["version"], JSON_SCHEMA_VERSION);
+        assert_eq!(value["total"], 5);
+        assert_eq!(value["commits"].as_array().unwrap().len(), 5);
+        assert_eq!(value["commits"][0]["type"], "feat");
+        assert_eq!(value["commits"][0]["scope"], "repo");
+        assert_eq!(value["types"]["info"]["feat"]["count"], 2);
+        assert_eq!(value["scopes"]["total"], 4);
+        assert_eq!(value["commits"][0]["breaking"], false);
+        assert_eq!(value["breaking"]["total"], 0);
+        assert_eq!(value["authors"][0]["name"], "erencam");
+        assert_eq!(value["authors"][0]["commits"], 4.0);
+        assert!(value["ownerships"].is_null());
+        assert!(value["unownedFiles"].is_null());
+        assert!(value["crossTeam"].is_null());
+    }
+}
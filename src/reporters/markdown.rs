@@ -0,0 +1,236 @@
+use std::io::Write;
+
+use crate::{commit::CommitBucket, config::Config, customerror::Result, ownerships::Ownerships};
+
+use super::{
+    open_output,
+    sections::{build_sections, Align, Section, Table},
+    Reporter,
+};
+
+/// Room kept free for the truncation notices.
+const NOTICE_RESERVE: usize = 160;
+
+/// Markdown reporter, meant to be posted as a pull request comment or a wiki page.
+pub struct Markdown {}
+
+impl<'a> Reporter<'a> for Markdown {
+    fn output(
+        &self,
+        config: &Config,
+        report_info: &CommitBucket,
+        ownerships_info: &Option<Ownerships<'a>>,
+    ) -> Result<()> {
+        let sections = build_sections(config, report_info, ownerships_info);
+        let mut out = open_output(config)?;
+        out.write_all(render_markdown(&sections, config.max_report_size).as_bytes())?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// How much of a table `push_table` appended.
+#[derive(Debug, PartialEq)]
+enum Pushed {
+    All,
+    /// Some rows, followed by a note on the rows left out.
+    Partly,
+    /// Nothing, not even the header fits.
+    Nothing,
+}
+
+/// Renders the sections, dropping rows, tables and sections that do not fit in `max_size` bytes.
+pub fn render_markdown(sections: &[Section], max_size: usize) -> String {
+    let limit = max_size.saturating_sub(NOTICE_RESERVE);
+    let mut doc = String::from("# git-lyze report\n");
+    let mut omitted_sections = 0;
+    let mut omitted_tables = 0;
+    let mut exhausted = false;
+
+    for section in sections.iter() {
+        let mut heading = format!("\n## {}\n", escape(&section.title));
+        if let Some(summary) = &section.summary {
+            heading.push_str(&format!("\n{}\n", escape(summary)));
+        }
+
+        if exhausted || doc.len() + heading.len() > limit {
+            exhausted = true;
+            omitted_sections += 1;
+            continue;
+        }
+        doc.push_str(&heading);
+
+        for (indx, table) in section.tables.iter().enumerate() {
+            let pushed = push_table(&mut doc, table, limit);
+            if pushed == Pushed::All {
+                continue;
+            }
+            exhausted = true;
+            // the rest of the section, and this table when not even its header fits
+            omitted_tables += section.tables.len() - indx - 1;
+            if pushed == Pushed::Nothing {
+                omitted_tables += 1;
+            }
+            break;
+        }
+    }
+
+    let omitted = [
+        (omitted_sections, "section(s)"),
+        (omitted_tables, "table(s)"),
+    ]
+    .iter()
+    .filter(|(count, _)| *count > 0)
+    .map(|(count, what)| format!("{} more {}", count, what))
+    .collect::<Vec<String>>();
+    if !omitted.is_empty() {
+        doc.push_str(&format!(
+            "\n> **Truncated:** {} omitted to stay under {} bytes.\n",
+            omitted.join(" and "),
+            max_size
+        ));
+    }
+
+    doc
+}
+
+/// Appends as many rows of the table as fit in `limit` bytes.
+fn push_table(doc: &mut String, table: &Table, limit: usize) -> Pushed {
+    let mut head = format!("\n### {}\n\n", escape(&table.title));
+    if table.rows.is_empty() {
+        head.push_str("_none_\n");
+        if doc.len() + head.len() > limit {
+            return Pushed::Nothing;
+        }
+        doc.push_str(&head);
+        return Pushed::All;
+    }
+
+    head.push_str(&row_line(table.columns.iter().map(|c| c.title.as_str())));
+    head.push_str(&format!(
+        "|{}\n",
+        table
+            .columns
+            .iter()
+            .map(|c| match c.align {
+                Align::Left => " --- |",
+                Align::Right => " ---: |",
+            })
+            .collect::<String>()
+    ));
+
+    if doc.len() + head.len() > limit {
+        return Pushed::Nothing;
+    }
+    doc.push_str(&head);
+
+    for (indx, row) in table.rows.iter().enumerate() {
+        let line = row_line(row.iter().map(|c| c.as_str()));
+        if doc.len() + line.len() > limit {
+            doc.push_str(&format!(
+                "\n_{} more row(s) not shown._\n",
+                table.rows.len() - indx
+            ));
+            return Pushed::Partly;
+        }
+        doc.push_str(&line);
+    }
+
+    Pushed::All
+}
+
+fn row_line<'a>(cells: impl Iterator<Item = &'a str>) -> String {
+    format!(
+        "|{}\n",
+        cells
+            .map(|cell| format!(" {} |", escape(cell)))
+            .collect::<String>()
+    )
+}
+
+fn escape(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::reporters::sections::Column;
+    use crate::test_utils::{mock_repo_config, setup_repo};
+
+    #[test]
+    fn can_render_tables() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo, example_commit_message) = setup_repo(&temp_dir);
+        let config = mock_repo_config(&temp_dir, None);
+        let bucket = CommitBucket::build(&repo, example_commit_message.as_str(), &config)
+            .expect("Failed to build commit bucket");
+
+        let doc = render_markdown(
+            &build_sections(&config, &bucket, &None),
+            config.max_report_size,
+        );
+
+        assert!(doc.starts_with("# git-lyze report\n\n## Summary\n\n5 commits\n"));
+        assert!(
+            doc.contains("| type | count | freq |\n| --- | ---: | ---: |\n| feat | 2 | 50.0% |\n")
+        );
+        assert!(!doc.contains("Truncated"));
+    }
+
+    #[test]
+    fn can_truncate_to_max_size() {
+        let mut table = Table::new(
+            "Files",
+            vec![Column::left("path"), Column::right("total changes")],
+        );
+        for indx in 0..100 {
+            table.push(vec![format!("src/file_{}.rs", indx), indx.to_string()]);
+        }
+        let section = Section {
+            title: "Summary".to_string(),
+            summary: None,
+            tables: vec![table],
+        };
+        let sections = vec![section.clone(), section];
+
+        let doc = render_markdown(&sections, 1024);
+
+        assert!(doc.len() <= 1024);
+        assert!(doc.contains("more row(s) not shown._"));
+        assert!(doc.contains("**Truncated:** 1 more section(s) omitted"));
+    }
+
+    #[test]
+    fn can_count_omitted_tables() {
+        let table = |title: &str, rows: usize| {
+            let mut table = Table::new(title, vec![Column::left("path")]);
+            for indx in 0..rows {
+                table.push(vec![format!("src/file_{}.rs", indx)]);
+            }
+            table
+        };
+        let section = Section {
+            title: "Files".to_string(),
+            summary: None,
+            tables: vec![table("first", 20), table("second", 40), table("third", 1)],
+        };
+
+        // room for the first table, not for the header of the second one
+        let first = render_markdown(
+            &[Section {
+                tables: vec![table("first", 20)],
+                ..section.clone()
+            }],
+            usize::MAX,
+        );
+        let doc = render_markdown(&[section], first.len() + NOTICE_RESERVE + 10);
+
+        assert!(doc.contains("| src/file_19.rs |"));
+        assert!(!doc.contains("### second"));
+        assert!(!doc.contains("not shown"));
+        assert!(doc.contains("**Truncated:** 2 more table(s) omitted"));
+    }
+}
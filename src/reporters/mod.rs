@@ -0,0 +1,96 @@
+pub mod csv;
+pub mod html;
+pub mod json;
+pub mod markdown;
+pub mod sections;
+pub mod stdout;
+pub mod tui;
+
+use std::{
+    fs::File,
+    io::{self, BufWriter, IsTerminal, Write},
+};
+
+use log::info;
+
+use crate::{
+    commit::CommitBucket,
+    config::{Config, OutputType},
+    customerror::Result,
+    ownerships::Ownerships,
+};
+
+pub use csv::Csv;
+pub use html::Html;
+pub use json::Json;
+pub use markdown::Markdown;
+pub use stdout::Stdout;
+pub use tui::Tui;
+
+pub trait Reporter<'a> {
+    fn output(
+        &self,
+        config: &Config,
+        report_info: &CommitBucket,
+        ownerships_info: &Option<Ownerships<'a>>,
+    ) -> Result<()>;
+    // fn output_commit_bucket(&self, bucket: &CommitBucket);
+    // fn output_file_summs(&self, file_summs: &FileSumms);
+}
+
+pub struct BaseReporter<'a> {
+    config: &'a Config,
+    reporter: Box<dyn Reporter<'a>>,
+    bucket: &'a CommitBucket,
+    ownerships_info: Option<Ownerships<'a>>,
+}
+
+impl<'a> BaseReporter<'a> {
+    pub fn new(
+        config: &'a Config,
+        commit_bucket: &'a CommitBucket,
+        reporter: Box<dyn Reporter<'a>>,
+    ) -> Result<BaseReporter<'a>> {
+        let ownerships_info = config
+            .ownerships
+            .as_ref()
+            .map(|conf| Ownerships::build(conf, commit_bucket, config))
+            .transpose()?;
+
+        Ok(BaseReporter {
+            config,
+            reporter,
+            bucket: commit_bucket,
+            ownerships_info,
+        })
+    }
+
+    pub fn output(&self) -> Result<()> {
+        self.reporter
+            .output(self.config, self.bucket, &self.ownerships_info)
+    }
+}
+
+pub fn select_reporter<'a>(output_type: &OutputType) -> Box<dyn Reporter<'a>> {
+    match output_type {
+        OutputType::Json => Box::new(Json {}),
+        OutputType::Stdout => Box::new(Stdout {}),
+        OutputType::Csv => Box::new(Csv { delimiter: ',' }),
+        OutputType::Tsv => Box::new(Csv { delimiter: '\t' }),
+        OutputType::Markdown => Box::new(Markdown {}),
+        OutputType::Html => Box::new(Html {}),
+        OutputType::Tui if io::stdout().is_terminal() => Box::new(Tui {}),
+        OutputType::Tui => {
+            info!("stdout is not a terminal, falling back to the stdout reporter");
+            Box::new(Stdout {})
+        }
+    }
+}
+
+/// Opens the report destination, `--output-file` when given, stdout otherwise.
+pub fn open_output(config: &Config) -> Result<Box<dyn Write>> {
+    match &config.output_file {
+        Some(path) => Ok(Box::new(BufWriter::new(File::create(path)?))),
+        None => Ok(Box::new(BufWriter::new(io::stdout().lock()))),
+    }
+}
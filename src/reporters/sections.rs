@@ -0,0 +1,508 @@
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use colored::Color;
+
+use crate::{
+    authors::{AuthorStats, TopEntry},
+    commit::{BucketInfo, CommitBucket, Freq},
+    config::{AuthorWeighting, Config},
+    ownerships::{CrossTeamChanges, Ownerships, TeamContributions, UnownedFiles},
+    revert::RevertRate,
+    utils::{format_duration, format_weight, map_file_summs},
+};
+
+/// Commits listed in the cross-team section, the total is given by its summary.
+const CROSS_TEAM_COMMITS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+pub struct Column {
+    pub title: String,
+    pub align: Align,
+    pub color: Option<Color>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub title: String,
+    pub columns: Vec<Column>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// A titled group of tables, rendered as one block by the text based reporters.
+#[derive(Debug, Clone)]
+pub struct Section {
+    pub title: String,
+    pub summary: Option<String>,
+    pub tables: Vec<Table>,
+}
+
+impl Column {
+    pub fn left(title: &str) -> Self {
+        Column {
+            title: title.to_string(),
+            align: Align::Left,
+            color: None,
+        }
+    }
+
+    pub fn right(title: &str) -> Self {
+        Column {
+            title: title.to_string(),
+            align: Align::Right,
+            color: None,
+        }
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl Table {
+    pub fn new(title: &str, columns: Vec<Column>) -> Self {
+        Table {
+            title: title.to_string(),
+            columns,
+            rows: vec![],
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    /// Display width of every column, including the header.
+    pub fn widths(&self) -> Vec<usize> {
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(indx, column)| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(indx))
+                    .map(|cell| cell.chars().count())
+                    .chain([column.title.chars().count()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+}
+
+pub fn build_sections(
+    config: &Config,
+    bucket: &CommitBucket,
+    ownerships_info: &Option<Ownerships>,
+) -> Vec<Section> {
+    let mut sections = vec![Section {
+        title: "Summary".to_string(),
+        summary: Some(format!("{} commits", bucket.info.total)),
+        tables: bucket_tables(config, &bucket.info),
+    }];
+
+    if !bucket.info.authors.is_empty() {
+        sections.push(Section {
+            title: "Authors".to_string(),
+            summary: Some(format!(
+                "{} authors, co-authored commits {}",
+                bucket.info.authors.len(),
+                match config.author_weighting {
+                    AuthorWeighting::Full => "credited to every participant",
+                    AuthorWeighting::Split => "split between the participants",
+                    AuthorWeighting::Primary => "credited to the author only",
+                }
+            )),
+            tables: vec![
+                authors_table(&bucket.info.authors),
+                author_focus_table(&bucket.info.authors),
+            ],
+        });
+    }
+
+    if bucket.info.reverts.total > 0 {
+        sections.push(reverts_section(bucket));
+    }
+
+    if bucket.info.breaking.total > 0 {
+        sections.push(breaking_section(bucket));
+    }
+
+    if let Some(ownerships) = ownerships_info {
+        if ownerships.unowned.total > 0 {
+            sections.push(unowned_section(&ownerships.unowned));
+        }
+        for ow_bucket in ownerships.ow_buckets.iter() {
+            let mut tables = bucket_tables(config, &ow_bucket.cm_bucket.info);
+            if let Some(contributions) = &ow_bucket.contributions {
+                tables.push(contributions_table(contributions));
+            }
+            tables.push(authors_table(&ow_bucket.cm_bucket.info.authors));
+
+            sections.push(Section {
+                title: format!("Ownership: {}", ow_bucket.config.name),
+                summary: Some(format!(
+                    "{} commits changing its files, {} of them also change files of other groups",
+                    ow_bucket.cm_bucket.info.total, ow_bucket.shared_commits
+                )),
+                tables,
+            });
+        }
+        let cross_team = &ownerships.cross_team;
+        if !cross_team.commits.is_empty() || !cross_team.pairs.is_empty() {
+            sections.push(cross_team_section(cross_team));
+        }
+    }
+
+    sections
+}
+
+fn bucket_tables(config: &Config, info: &BucketInfo) -> Vec<Table> {
+    let mut tables = vec![
+        freq_table("Types", "type", &info.types),
+        freq_table("Scopes", "scope", &info.scopes),
+    ];
+    if info.trailers.total > 0 {
+        tables.push(freq_table("Trailers", "trailer", &info.trailers));
+    }
+    tables.push(files_table(config, info));
+
+    tables
+}
+
+pub fn freq_table(title: &str, key_title: &str, freq: &Freq) -> Table {
+    let mut table = Table::new(
+        title,
+        vec![
+            Column::left(key_title),
+            Column::right("count"),
+            Column::right("freq"),
+        ],
+    );
+
+    let mut entries = freq.info.iter().collect::<Vec<_>>();
+    entries.sort_by(|a, b| b.1.count.cmp(&a.1.count).then_with(|| a.0.cmp(b.0)));
+
+    for (key, info) in entries {
+        table.push(vec![
+            key.to_string(),
+            info.count.to_string(),
+            format!("{:.1}%", info.freq * 100.0),
+        ]);
+    }
+
+    table
+}
+
+pub fn files_table(config: &Config, info: &BucketInfo) -> Table {
+    let mut table = Table::new(
+        "Files",
+        vec![
+            Column::left("path"),
+            Column::right("total changes"),
+            Column::right("insertions").with_color(Color::Green),
+            Column::right("deletions").with_color(Color::Red),
+        ],
+    );
+
+    for file_summ in map_file_summs(config, &info.file_summs) {
+        table.push(vec![
+            file_summ.path.to_string(),
+            file_summ.total_changes.to_string(),
+            format!("{}+", file_summ.inserted),
+            format!("{}-", file_summ.deleted),
+        ]);
+    }
+
+    table
+}
+
+pub fn authors_table(authors: &[AuthorStats]) -> Table {
+    let mut table = Table::new(
+        "Authors",
+        vec![
+            Column::left("author"),
+            Column::right("commits"),
+            Column::right("freq"),
+            Column::right("co-authored"),
+            Column::right("insertions").with_color(Color::Green),
+            Column::right("deletions").with_color(Color::Red),
+            Column::right("files"),
+            Column::right("active days"),
+            Column::right("first commit"),
+            Column::right("last commit"),
+        ],
+    );
+
+    for author in authors.iter() {
+        table.push(vec![
+            author.name.clone(),
+            format_weight(author.commits),
+            format!("{:.1}%", author.freq * 100.0),
+            author.co_authored.to_string(),
+            format!("{}+", format_weight(author.insertions.round())),
+            format!("{}-", format_weight(author.deletions.round())),
+            author.files_touched.to_string(),
+            author.active_days.to_string(),
+            format_day(author.first_commit),
+            format_day(author.last_commit),
+        ]);
+    }
+
+    table
+}
+
+pub fn author_focus_table(authors: &[AuthorStats]) -> Table {
+    let mut table = Table::new(
+        "Author focus",
+        vec![
+            Column::left("author"),
+            Column::left("top types"),
+            Column::left("top scopes"),
+            Column::left("top files (changed lines)"),
+        ],
+    );
+
+    for author in authors.iter() {
+        table.push(vec![
+            author.name.clone(),
+            TopEntry::join(&author.top_types),
+            TopEntry::join(&author.top_scopes),
+            TopEntry::join(&author.top_files),
+        ]);
+    }
+
+    table
+}
+
+fn format_day(seconds: i64) -> String {
+    DateTime::from_timestamp(seconds, 0)
+        .map_or("-".to_string(), |time| time.format("%Y-%m-%d").to_string())
+}
+
+fn reverts_section(bucket: &CommitBucket) -> Section {
+    let reverts = &bucket.info.reverts;
+    let summary_of = |id: &Option<String>| {
+        id.as_ref()
+            .and_then(|id| bucket.commits.iter().find(|commit| &commit.id == id))
+            .map_or("(not in range)".to_string(), |commit| {
+                commit.summary.clone()
+            })
+    };
+
+    let mut reverted = Table::new(
+        "Reverted commits",
+        vec![
+            Column::left("revert"),
+            Column::left("reverted"),
+            Column::right("time to revert"),
+        ],
+    );
+    for revert in reverts.reverts.iter() {
+        reverted.push(vec![
+            summary_of(&Some(revert.id.clone())),
+            summary_of(&revert.reverted_id),
+            revert
+                .time_to_revert
+                .map_or("-".to_string(), format_duration),
+        ]);
+    }
+
+    Section {
+        title: "Reverts".to_string(),
+        summary: Some(format!(
+            "{} reverts, average time to revert {}",
+            reverts.total,
+            reverts
+                .avg_time_to_revert
+                .map_or("-".to_string(), format_duration)
+        )),
+        tables: vec![
+            reverted,
+            revert_rate_table("Revert rate by type", "type", &reverts.types),
+            revert_rate_table("Revert rate by scope", "scope", &reverts.scopes),
+            revert_rate_table("Revert rate by author", "author", &reverts.authors),
+        ],
+    }
+}
+
+fn unowned_section(unowned: &UnownedFiles) -> Section {
+    let mut table = Table::new(
+        "Busiest unowned files",
+        vec![
+            Column::left("path"),
+            Column::right("total changes"),
+            Column::right("insertions").with_color(Color::Green),
+            Column::right("deletions").with_color(Color::Red),
+        ],
+    );
+    for file in unowned.files.iter() {
+        table.push(vec![
+            file.path.to_string(),
+            file.total_changes.to_string(),
+            format!("{}+", file.inserted),
+            format!("{}-", file.deleted),
+        ]);
+    }
+
+    Section {
+        title: "Unowned files".to_string(),
+        summary: Some(format!(
+            "{} changed files without an ownership group, {:.1}% of the changed lines",
+            unowned.total,
+            unowned.freq * 100.0
+        )),
+        tables: vec![table],
+    }
+}
+
+fn cross_team_section(cross_team: &CrossTeamChanges) -> Section {
+    let mut pairs = Table::new(
+        "Changes outside the author's team",
+        vec![
+            Column::left("author team"),
+            Column::left("owning team"),
+            Column::right("commits"),
+            Column::right("changed lines"),
+            Column::left("authors"),
+        ],
+    );
+    for pair in cross_team.pairs.iter() {
+        pairs.push(vec![
+            pair.author_team.clone(),
+            pair.owning_team.clone(),
+            pair.commits.to_string(),
+            pair.changed_lines.to_string(),
+            pair.authors.join(", "),
+        ]);
+    }
+
+    let mut commits = Table::new(
+        "Commits changing files of several groups",
+        vec![
+            Column::left("commit"),
+            Column::left("summary"),
+            Column::left("groups"),
+        ],
+    );
+    for cm in cross_team.commits.iter().take(CROSS_TEAM_COMMITS) {
+        commits.push(vec![
+            cm.id.chars().take(7).collect(),
+            cm.summary.clone(),
+            cm.groups.join(", "),
+        ]);
+    }
+
+    Section {
+        title: "Cross-team changes".to_string(),
+        summary: Some(format!(
+            "{} commits change files of several groups, {} pairs of groups where members of \
+             one changed files of the other",
+            cross_team.commits.len(),
+            cross_team.pairs.len()
+        )),
+        tables: vec![pairs, commits],
+    }
+}
+
+fn contributions_table(contributions: &TeamContributions) -> Table {
+    let mut table = Table::new(
+        "Contributions",
+        vec![
+            Column::left("by"),
+            Column::right("commits"),
+            Column::right("insertions").with_color(Color::Green),
+            Column::right("deletions").with_color(Color::Red),
+        ],
+    );
+    for (by, contribution) in [
+        ("in team", &contributions.in_team),
+        ("out of team", &contributions.out_of_team),
+    ] {
+        table.push(vec![
+            by.to_string(),
+            contribution.commits.to_string(),
+            contribution.insertions.to_string(),
+            contribution.deletions.to_string(),
+        ]);
+    }
+
+    table
+}
+
+fn breaking_section(bucket: &CommitBucket) -> Section {
+    let breaking = &bucket.info.breaking;
+
+    let mut changes = Table::new(
+        "Breaking changes",
+        vec![
+            Column::left("commit"),
+            Column::left("scope"),
+            Column::left("description").with_color(Color::Red),
+        ],
+    );
+    for change in breaking.changes.iter() {
+        changes.push(vec![
+            change.id.chars().take(7).collect(),
+            change.scope.clone(),
+            change.description.clone(),
+        ]);
+    }
+
+    Section {
+        title: "Breaking changes".to_string(),
+        summary: Some(format!("{} breaking changes", breaking.total)),
+        tables: vec![
+            changes,
+            count_table("Breaking changes by scope", "scope", &breaking.scopes),
+            count_table("Breaking changes by month", "month", &breaking.months),
+        ],
+    }
+}
+
+fn count_table(title: &str, key_title: &str, counts: &BTreeMap<String, u32>) -> Table {
+    let mut table = Table::new(title, vec![Column::left(key_title), Column::right("count")]);
+    for (key, count) in counts.iter() {
+        table.push(vec![key.to_string(), count.to_string()]);
+    }
+
+    table
+}
+
+fn revert_rate_table(title: &str, key_title: &str, rates: &BTreeMap<String, RevertRate>) -> Table {
+    let mut table = Table::new(
+        title,
+        vec![
+            Column::left(key_title),
+            Column::right("commits"),
+            Column::right("reverted").with_color(Color::Red),
+            Column::right("rate"),
+        ],
+    );
+
+    let mut entries = rates.iter().collect::<Vec<_>>();
+    entries.sort_by(|a, b| {
+        b.1.rate
+            .total_cmp(&a.1.rate)
+            .then_with(|| b.1.reverted.cmp(&a.1.reverted))
+            .then_with(|| a.0.cmp(b.0))
+    });
+
+    for (key, rate) in entries {
+        table.push(vec![
+            key.to_string(),
+            rate.commits.to_string(),
+            rate.reverted.to_string(),
+            format!("{:.1}%", rate.rate * 100.0),
+        ]);
+    }
+
+    table
+}
@@ -0,0 +1,170 @@
+use std::io::{self, IsTerminal, Write};
+
+use colored::{Color, Colorize};
+
+use crate::{
+    commit::CommitBucket,
+    config::{ColorChoice, Config},
+    customerror::Result,
+    ownerships::Ownerships,
+};
+
+use super::{
+    open_output,
+    sections::{build_sections, Align, Section, Table},
+    Reporter,
+};
+
+/// Plain text reporter, prints the report as aligned tables.
+pub struct Stdout {}
+
+impl<'a> Reporter<'a> for Stdout {
+    fn output(
+        &self,
+        config: &Config,
+        report_info: &CommitBucket,
+        ownerships_info: &Option<Ownerships<'a>>,
+    ) -> Result<()> {
+        let use_color = match config.color {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => config.output_file.is_none() && io::stdout().is_terminal(),
+        };
+        let _forced_color = matches!(config.color, ColorChoice::Always).then(ForcedColor::new);
+
+        let sections = build_sections(config, report_info, ownerships_info);
+        let mut out = open_output(config)?;
+        write_sections(&mut out, &sections, use_color)?;
+        out.flush()?;
+        Ok(())
+    }
+}
+
+/// Colors output even when `colored` would not, e.g. for a pipe, until dropped.
+struct ForcedColor;
+
+impl ForcedColor {
+    fn new() -> Self {
+        colored::control::set_override(true);
+        ForcedColor
+    }
+}
+
+impl Drop for ForcedColor {
+    fn drop(&mut self) {
+        colored::control::unset_override();
+    }
+}
+
+pub fn write_sections(out: &mut dyn Write, sections: &[Section], use_color: bool) -> Result<()> {
+    for (indx, section) in sections.iter().enumerate() {
+        if indx > 0 {
+            writeln!(out)?;
+        }
+        writeln!(
+            out,
+            "{}",
+            paint(&format!("== {} ==", section.title), use_color, |s| s
+                .color(Color::Yellow)
+                .bold())
+        )?;
+        if let Some(summary) = &section.summary {
+            writeln!(out, "{}", summary)?;
+        }
+
+        for table in section.tables.iter() {
+            writeln!(out)?;
+            write_table(out, table, use_color)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn write_table(out: &mut dyn Write, table: &Table, use_color: bool) -> Result<()> {
+    writeln!(out, "{}", paint(&table.title, use_color, |s| s.bold()))?;
+
+    if table.rows.is_empty() {
+        writeln!(out, "  (none)")?;
+        return Ok(());
+    }
+
+    let widths = table.widths();
+
+    let header = table
+        .columns
+        .iter()
+        .zip(widths.iter())
+        .map(|(column, width)| {
+            let cell = pad(&column.title, *width, column.align);
+            paint(&cell, use_color, |s| s.bold().underline())
+        })
+        .collect::<Vec<_>>();
+    writeln!(out, "  {}", header.join("  ").trim_end())?;
+
+    for row in table.rows.iter() {
+        let cells = table
+            .columns
+            .iter()
+            .zip(widths.iter())
+            .enumerate()
+            .map(|(indx, (column, width))| {
+                let cell = pad(
+                    row.get(indx).map_or("", |c| c.as_str()),
+                    *width,
+                    column.align,
+                );
+                match column.color {
+                    Some(color) => paint(&cell, use_color, |s| s.color(color)),
+                    None => cell,
+                }
+            })
+            .collect::<Vec<_>>();
+        writeln!(out, "  {}", cells.join("  ").trim_end())?;
+    }
+
+    Ok(())
+}
+
+fn pad(value: &str, width: usize, align: Align) -> String {
+    match align {
+        Align::Left => format!("{:<width$}", value, width = width),
+        Align::Right => format!("{:>width$}", value, width = width),
+    }
+}
+
+fn paint(value: &str, use_color: bool, style: impl Fn(&str) -> colored::ColoredString) -> String {
+    if use_color {
+        style(value).to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::test_utils::{mock_repo_config, setup_repo};
+
+    #[test]
+    fn can_write_aligned_tables() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo, example_commit_message) = setup_repo(&temp_dir);
+        let config = mock_repo_config(&temp_dir, None);
+        let bucket = CommitBucket::build(&repo, example_commit_message.as_str(), &config)
+            .expect("Failed to build commit bucket");
+
+        let mut out: Vec<u8> = vec![];
+        write_sections(&mut out, &build_sections(&config, &bucket, &None), false)
+            .expect("Failed to write sections");
+        let out = String::from_utf8(out).unwrap();
+
+        assert!(out.starts_with("== Summary ==\n5 commits\n"));
+        assert!(out
+            .contains("Types\n  type  count   freq\n  feat      2  50.0%\n  test      2  50.0%\n"));
+        assert!(out.contains("Scopes\n  scope   count   freq\n  main        2  50.0%\n"));
+        assert!(!out.contains('\u{1b}'));
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{
+    commit::CommitBucket,
+    config::Config,
+    customerror::Result,
+    ownerships::Ownerships,
+    window::{app::App, screen::render_screen},
+};
+
+use super::Reporter;
+
+pub struct Tui {}
+
+impl<'a> Reporter<'a> for Tui {
+    fn output(
+        &self,
+        config: &Config,
+        report_info: &CommitBucket,
+        ownerships_info: &Option<Ownerships<'a>>,
+    ) -> Result<()> {
+        let mut app = App::new(config, report_info, ownerships_info.clone());
+
+        render_screen(&mut app)
+    }
+}
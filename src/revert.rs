@@ -0,0 +1,283 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ops::Bound;
+
+use regex::Regex;
+use serde::Serialize;
+
+use crate::commit::CommitInfo;
+
+const REVERT_INDICATOR: &str = "revert_indicator";
+const MESSAGE_INDICATOR: &str = "message";
+
+#[derive(Debug)]
+pub struct RevertDetector {
+    regex: Regex,
+    body_regex: Regex,
+}
+
+/// The commit a revert points to, either by hash or by the reverted summary.
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct RevertRef {
+    pub id: Option<String>,
+    pub summary: Option<String>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RevertInfo {
+    pub id: String,
+    pub reverted_id: Option<String>,
+    pub time_to_revert: Option<i64>,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct RevertRate {
+    pub commits: u32,
+    pub reverted: u32,
+    pub rate: f64,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RevertSummary {
+    pub total: u32,
+    pub reverts: Vec<RevertInfo>,
+    pub avg_time_to_revert: Option<i64>,
+    pub types: BTreeMap<String, RevertRate>,
+    pub scopes: BTreeMap<String, RevertRate>,
+    pub authors: BTreeMap<String, RevertRate>,
+}
+
+impl RevertDetector {
+    /// Builds the detector from a pattern like `revert_indicator "message"`.
+    pub fn build(revert_message_pattern: &str) -> RevertDetector {
+        let regex_pattern = regex::escape(revert_message_pattern)
+            .replacen(REVERT_INDICATOR, r"(?i:revert)", 1)
+            .replacen(MESSAGE_INDICATOR, r"(?P<message>.+)", 1);
+
+        RevertDetector {
+            regex: Regex::new(&format!("^{}$", regex_pattern)).unwrap(),
+            body_regex: Regex::new(r"(?m)^This reverts commit (?P<id>[0-9a-fA-F]{7,40})").unwrap(),
+        }
+    }
+
+    pub fn detect(&self, summary: &str, body: &str) -> Option<RevertRef> {
+        let id = self
+            .body_regex
+            .captures(body)
+            .and_then(|captures| captures.name("id"))
+            .map(|m| m.as_str().to_lowercase());
+
+        let summary_match = self.regex.captures(summary);
+        if id.is_none() && summary_match.is_none() {
+            return None;
+        }
+
+        Some(RevertRef {
+            id,
+            summary: summary_match
+                .and_then(|captures| captures.name("message"))
+                .map(|m| m.as_str().to_string()),
+        })
+    }
+}
+
+/// Fills in the hash of reverts that only name the reverted summary, linking each one to the
+/// latest commit with that summary that is not newer than the revert.
+pub fn link_reverts(commits: &mut [CommitInfo]) {
+    let mut summary_ids: HashMap<String, Vec<(i64, String)>> = HashMap::new();
+    for commit in commits.iter() {
+        summary_ids
+            .entry(commit.summary.clone())
+            .or_default()
+            .push((commit.time, commit.id.clone()));
+    }
+    // sorted, so the ids starting with an abbreviated hash follow it
+    let ids = commits
+        .iter()
+        .map(|commit| commit.id.clone())
+        .collect::<BTreeSet<String>>();
+
+    for commit in commits.iter_mut() {
+        let Some(revert_of) = commit.revert_of.as_mut() else {
+            continue;
+        };
+
+        revert_of.id = match &revert_of.id {
+            Some(id) => ids
+                .range::<str, _>((Bound::Included(id.as_str()), Bound::Unbounded))
+                .next()
+                .filter(|full_id| full_id.starts_with(id.as_str()))
+                .cloned()
+                .or(Some(id.clone())),
+            None => revert_of
+                .summary
+                .as_ref()
+                .and_then(|summary| summary_ids.get(summary))
+                .and_then(|candidates| {
+                    candidates
+                        .iter()
+                        .filter(|(time, id)| *time <= commit.time && *id != commit.id)
+                        // ties on time go to the smaller hash, whatever the commit order
+                        .max_by(|a, b| a.0.cmp(&b.0).then_with(|| b.1.cmp(&a.1)))
+                })
+                .map(|(_, id)| id.clone()),
+        };
+    }
+}
+
+impl RevertSummary {
+    pub fn collect(commits: &[CommitInfo]) -> RevertSummary {
+        let by_id: HashMap<&str, &CommitInfo> = commits
+            .iter()
+            .map(|commit| (commit.id.as_str(), commit))
+            .collect();
+
+        let mut summary = RevertSummary::default();
+        let mut reverted_ids: HashSet<&str> = HashSet::new();
+
+        for commit in commits.iter() {
+            let Some(revert_of) = &commit.revert_of else {
+                continue;
+            };
+            let reverted = revert_of.id.as_ref().and_then(|id| by_id.get(id.as_str()));
+            if let Some(reverted) = reverted {
+                reverted_ids.insert(&reverted.id);
+            }
+
+            summary.total += 1;
+            summary.reverts.push(RevertInfo {
+                id: commit.id.clone(),
+                reverted_id: revert_of.id.clone(),
+                time_to_revert: reverted.map(|reverted| commit.time - reverted.time),
+            });
+        }
+
+        let times = summary
+            .reverts
+            .iter()
+            .filter_map(|revert| revert.time_to_revert)
+            .collect::<Vec<i64>>();
+        if !times.is_empty() {
+            summary.avg_time_to_revert = Some(times.iter().sum::<i64>() / times.len() as i64);
+        }
+
+        for commit in commits.iter().filter(|commit| commit.revert_of.is_none()) {
+            let is_reverted = reverted_ids.contains(commit.id.as_str());
+            for (rates, key) in [
+                (&mut summary.types, &commit.type_),
+                (&mut summary.scopes, &commit.scope),
+                (&mut summary.authors, &commit.author.name),
+            ] {
+                if key.is_empty() {
+                    continue;
+                }
+                let rate = rates.entry(key.clone()).or_default();
+                rate.commits += 1;
+                if is_reverted {
+                    rate.reverted += 1;
+                }
+                rate.rate = rate.reverted as f64 / rate.commits as f64;
+            }
+        }
+
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_link_reverts_by_summary() {
+        let commit = |id: &str, summary: &str, time: i64| CommitInfo {
+            id: id.to_string(),
+            summary: summary.to_string(),
+            time,
+            ..Default::default()
+        };
+        let revert = CommitInfo {
+            revert_of: Some(RevertRef {
+                id: None,
+                summary: Some("feat: button".to_string()),
+            }),
+            ..commit("r", "Revert \"feat: button\"", 200)
+        };
+        let mut commits = vec![
+            commit("newer", "feat: button", 300),
+            revert,
+            commit("closest", "feat: button", 150),
+            commit("oldest", "feat: button", 100),
+        ];
+
+        let linked = |commits: &[CommitInfo]| {
+            commits
+                .iter()
+                .find(|commit| commit.id == "r")
+                .and_then(|commit| commit.revert_of.as_ref())
+                .and_then(|revert_of| revert_of.id.clone())
+        };
+        let mut reversed = commits.iter().rev().cloned().collect::<Vec<_>>();
+        link_reverts(&mut commits);
+        link_reverts(&mut reversed);
+
+        assert_eq!(linked(&commits), Some("closest".to_string()));
+        assert_eq!(linked(&reversed), Some("closest".to_string()));
+    }
+
+    #[test]
+    fn can_link_reverts_by_abbreviated_hash() {
+        let revert = |id: &str, reverted: &str| CommitInfo {
+            id: id.to_string(),
+            revert_of: Some(RevertRef {
+                id: Some(reverted.to_string()),
+                summary: None,
+            }),
+            ..Default::default()
+        };
+        let mut commits = vec![
+            revert("f1", "a1b3"),
+            revert("f2", "0ff"),
+            CommitInfo {
+                id: "a1b3c5".to_string(),
+                ..Default::default()
+            },
+            CommitInfo {
+                id: "a1b2c4".to_string(),
+                ..Default::default()
+            },
+        ];
+        link_reverts(&mut commits);
+
+        let reverted_ids = commits
+            .iter()
+            .map(|commit| commit.revert_of.as_ref().and_then(|r| r.id.as_deref()))
+            .collect::<Vec<_>>();
+        assert_eq!(reverted_ids, vec![Some("a1b3c5"), Some("0ff"), None, None]);
+    }
+
+    #[test]
+    fn can_detect_reverts() {
+        let detector = RevertDetector::build("revert_indicator \"message\"");
+
+        assert_eq!(
+            detector.detect("Revert \"feat(main): test\"", ""),
+            Some(RevertRef {
+                id: None,
+                summary: Some("feat(main): test".to_string()),
+            })
+        );
+        assert_eq!(
+            detector.detect(
+                "undo the parser change",
+                "This reverts commit 1A2B3C4D5E.\n\nIt broke the build."
+            ),
+            Some(RevertRef {
+                id: Some("1a2b3c4d5e".to_string()),
+                summary: None,
+            })
+        );
+        assert_eq!(detector.detect("feat(main): revert button", ""), None);
+    }
+}
@@ -1,4 +1,4 @@
-use std::vec;
+use std::{fs, path::Path, vec};
 
 use clap::Parser;
 use git2::{Repository, Signature};
@@ -14,6 +14,17 @@ pub fn mock_config(args: Option<Vec<&str>>) -> Config {
     Config::try_parse_from(vec![""]).unwrap()
 }
 
+/// Config whose diff stats cache is written inside the temp dir of the test repository.
+pub fn mock_repo_config(temp_dir: &TempDir, args: Option<Vec<&str>>) -> Config {
+    let mut config = mock_config(args);
+    config.cache_path = temp_dir
+        .path()
+        .join(".lyze.cache.json")
+        .to_string_lossy()
+        .to_string();
+    config
+}
+
 pub fn setup_repo(temp_dir: &TempDir) -> (Repo, String) {
     let git_repo = Repository::init(temp_dir.path()).expect("Failed to create repository");
     let mut oid = git_repo
@@ -45,7 +56,7 @@ pub fn setup_repo(temp_dir: &TempDir) -> (Repo, String) {
                     &tree,
                     &[&git_repo.find_commit(oid).unwrap()],
                 )
-                .expect(format!("Failed to commit with message: {}", commit_message).as_str());
+                .unwrap_or_else(|_| panic!("Failed to commit with message: {}", commit_message));
         } else {
             oid = git_repo
                 .commit(
@@ -56,7 +67,7 @@ pub fn setup_repo(temp_dir: &TempDir) -> (Repo, String) {
                     &tree,
                     &[],
                 )
-                .expect(format!("Failed to commit with message: {}", commit_message).as_str());
+                .unwrap_or_else(|_| panic!("Failed to commit with message: {}", commit_message));
         }
     }
 
@@ -66,8 +77,103 @@ pub fn setup_repo(temp_dir: &TempDir) -> (Repo, String) {
     )
 }
 
-pub fn setup_cache_dir(path: &String) {
-    let mut cache = Cache::new(&path);
+/// Appends commits on top of HEAD of the repository in `temp_dir`.
+pub fn append_commits(temp_dir: &TempDir, commit_messages: &[&str]) {
+    let git_repo = Repository::open(temp_dir.path()).expect("Failed to open repository");
+    let signature = Signature::now("erencam", "erencam.dev@gmail.com").unwrap();
+
+    for commit_message in commit_messages {
+        let parent = git_repo
+            .head()
+            .and_then(|head| head.peel_to_commit())
+            .expect("Failed to get HEAD");
+        git_repo
+            .commit(
+                Some("HEAD"),
+                &signature,
+                &signature,
+                commit_message,
+                &parent.tree().expect("Failed to get the tree"),
+                &[&parent],
+            )
+            .unwrap_or_else(|_| panic!("Failed to commit with message: {}", commit_message));
+    }
+}
+
+/// Appends one commit per `(message, files)` on top of HEAD of the repository in `temp_dir`,
+/// writing each `(path, content)` to the work tree and staging it. Creates the repository and
+/// starts the history when there is none yet.
+pub fn commit_files(temp_dir: &TempDir, commits: &[(&str, &[(&str, &str)])]) {
+    let git_repo = Repository::open(temp_dir.path())
+        .or_else(|_| Repository::init(temp_dir.path()))
+        .expect("Failed to open repository");
+    let signature = Signature::now("erencam", "erencam.dev@gmail.com").unwrap();
+
+    for (commit_message, files) in commits {
+        let mut index = git_repo.index().expect("Failed to get index");
+        for (path, content) in files.iter() {
+            let full_path = temp_dir.path().join(path);
+            if let Some(parent) = full_path.parent() {
+                fs::create_dir_all(parent).expect("Failed to create directory");
+            }
+            fs::write(&full_path, content).expect("Failed to write file");
+            index
+                .add_path(Path::new(path))
+                .expect("Failed to stage file");
+        }
+        index.write().expect("Failed to write index");
+        let tree = git_repo
+            .find_tree(index.write_tree().expect("Failed to get oid"))
+            .expect("Failed to get the tree");
+        let parents = git_repo
+            .head()
+            .ok()
+            .and_then(|head| head.peel_to_commit().ok())
+            .into_iter()
+            .collect::<Vec<_>>();
+        git_repo
+            .commit(
+                Some("HEAD"),
+                &signature,
+                &signature,
+                commit_message,
+                &tree,
+                &parents.iter().collect::<Vec<_>>(),
+            )
+            .unwrap_or_else(|_| panic!("Failed to commit with message: {}", commit_message));
+    }
+}
+
+/// Repository of 12 commits each rewriting one of `f0.txt`, `f1.txt` and `f2.txt`, the `n`th
+/// commit with `n + 1` lines.
+pub fn setup_file_repo(temp_dir: &TempDir) -> Repo {
+    let commits = (0..12)
+        .map(|indx| {
+            (
+                format!("feat: commit {}", indx),
+                format!("f{}.txt", indx % 3),
+                "line\n".repeat(indx + 1),
+            )
+        })
+        .collect::<Vec<(String, String, String)>>();
+    let files = commits
+        .iter()
+        .map(|(_, path, content)| [(path.as_str(), content.as_str())])
+        .collect::<Vec<_>>();
+    commit_files(
+        temp_dir,
+        &commits
+            .iter()
+            .zip(files.iter())
+            .map(|((message, _, _), files)| (message.as_str(), &files[..]))
+            .collect::<Vec<_>>(),
+    );
+
+    Repo::init(temp_dir.path()).expect("Failed to init repo")
+}
+
+pub fn setup_cache_dir(path: &str) {
+    let mut cache = Cache::new(path);
     cache.set("foo".to_string(), "bar".to_string());
     drop(cache);
 }
@@ -32,12 +32,12 @@ impl<'a> Tracker<'a> {
     pub fn start(&mut self, title: &'a str) {
         self.start_time = Instant::now();
         self.title = title;
-        self.title_list.push(&title);
+        self.title_list.push(title);
     }
 
     pub fn stop(&mut self) {
         if let Some(opts) = &self.opts {
-            if opts.write_once.is_some_and(|write_once| write_once == true)
+            if opts.write_once.is_some_and(|write_once| write_once)
                 && self.title_list.contains(&self.title)
             {
                 return;
@@ -63,5 +63,5 @@ fn select_elapsed_time_color(e_time: &Duration) -> Color {
         return Color::BrightRed;
     }
 
-    return Color::BrightGreen;
+    Color::BrightGreen
 }
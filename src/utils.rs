@@ -6,18 +6,18 @@ use crate::{
 };
 use chrono::{DateTime, NaiveDate, NaiveDateTime};
 
-pub fn parse_date(value: &String, format: &String, format_type: &DateFormatType) -> Option<i64> {
+pub fn parse_date(value: &str, format: &str, format_type: &DateFormatType) -> Option<i64> {
     match format_type {
-        DateFormatType::DateOnly => NaiveDate::parse_from_str(&value, &format)
+        DateFormatType::DateOnly => NaiveDate::parse_from_str(value, format)
             .ok()
             .map(|date| date.and_hms_opt(0, 0, 0).unwrap_or_default())
             .map(|date| date.timestamp()),
 
-        DateFormatType::DateAndTime => NaiveDateTime::parse_from_str(&value, &format)
+        DateFormatType::DateAndTime => NaiveDateTime::parse_from_str(value, format)
             .ok()
             .map(|date| date.timestamp()),
 
-        DateFormatType::DateTimeAndTimezone => DateTime::parse_from_str(&value, format)
+        DateFormatType::DateTimeAndTimezone => DateTime::parse_from_str(value, format)
             .ok()
             .map(|date| date.timestamp()),
     }
@@ -29,9 +29,12 @@ pub fn map_file_summs<'a>(
 ) -> Vec<&'a FileStatInfo> {
     let mut summ = summ_map.values().collect::<Vec<&FileStatInfo>>();
 
-    summ.sort_by(|a, b| match conf.sort_files {
-        SortType::Asc => a.total_changes.abs().cmp(&b.total_changes.abs()),
-        SortType::Desc => b.total_changes.abs().cmp(&a.total_changes.abs()),
+    summ.sort_by(|a, b| {
+        match conf.sort_files {
+            SortType::Asc => a.total_changes.abs().cmp(&b.total_changes.abs()),
+            SortType::Desc => b.total_changes.abs().cmp(&a.total_changes.abs()),
+        }
+        .then_with(|| a.path.cmp(&b.path))
     });
 
     if conf.file_count.is_none() {
@@ -47,6 +50,31 @@ pub fn map_file_summs<'a>(
     summ[..boundry].to_vec()
 }
 
+/// Formats seconds as the two largest units, e.g., `3d 4h`.
+pub fn format_duration(seconds: i64) -> String {
+    let seconds = seconds.abs();
+    let (days, hours, minutes) = (
+        seconds / 86_400,
+        seconds % 86_400 / 3_600,
+        seconds % 3_600 / 60,
+    );
+
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{}s", seconds),
+        (0, 0, m) => format!("{}m", m),
+        (0, h, m) => format!("{}h {}m", h, m),
+        (d, h, _) => format!("{}d {}h", d, h),
+    }
+}
+
+/// Formats a weighted count, whole numbers without decimals, e.g., `3` or `2.50`.
+pub fn format_weight(value: f64) -> String {
+    match value.fract() == 0.0 {
+        true => format!("{:.0}", value),
+        false => format!("{:.2}", value),
+    }
+}
+
 pub fn set_log_env(log_level: &LogLevel) {
     let key = "RUST_LOG";
     match log_level {
@@ -1,36 +1,220 @@
+use std::rc::Rc;
+
 use ratatui::widgets::TableState;
 
-use crate::{commit::FileStatInfo, config::SortType};
+use crate::{
+    authors::AuthorStats,
+    commit::{BucketInfo, CommitBucket, CommitInfo, FileStatInfo},
+    config::{Config, SortType},
+    filter::CommitFilter,
+    ownerships::Ownerships,
+    utils::map_file_summs,
+    window::timeline::Timeline,
+};
+
+/// Column the authors table is sorted by, cycled with `s`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum AuthorSort {
+    #[default]
+    Commits,
+    Insertions,
+    Deletions,
+    Files,
+    ActiveDays,
+    Name,
+}
+
+/// Commit counts per scope (rows) and type (columns), busiest first.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TypeScopeMatrix {
+    pub types: Vec<String>,
+    pub scopes: Vec<String>,
+    pub counts: Vec<Vec<u64>>,
+}
 
 #[derive(Debug, Default, Clone)]
 pub struct App<'a> {
     pub should_quit: bool,
-    pub counter: u8,
+    /// Keybindings overlay, toggled with `?`.
+    pub show_help: bool,
     pub active_tab: usize,
     pub titles: Vec<&'a str>,
-    pub file_summs: Vec<&'a FileStatInfo>,
+    pub config: Option<&'a Config>,
+    /// Every loaded commit, the tabs show the ones left by `filter`.
+    pub all_commits: &'a [CommitInfo],
+    pub file_summs: Vec<FileStatInfo>,
     pub sort_file_summs: SortType,
     pub file_table_state: TableState,
     pub types: Vec<(String, u64)>,
+    pub scopes: Vec<(String, u64)>,
+    pub type_scopes: TypeScopeMatrix,
+    pub trailers: Vec<(String, u64)>,
+    pub timeline: Timeline,
+    pub authors: Vec<AuthorStats>,
+    pub sort_authors: AuthorSort,
+    pub sort_authors_order: SortType,
+    pub author_table_state: TableState,
+    pub commits: Rc<[CommitInfo]>,
+    pub commit_table_state: TableState,
+    /// Path whose commits replace the files table, opened with `Enter`.
+    pub file_drill_down: Option<String>,
+    pub file_commit_table_state: TableState,
+    /// Expression being typed in the filter prompt, opened with `/`.
+    pub filter_input: Option<String>,
+    /// Expression of the applied filter.
+    pub filter: String,
+    pub filter_error: Option<String>,
+    /// Groups of the Ownership tab, none when no ownership is configured.
+    pub ownerships: Option<Ownerships<'a>>,
+    /// First group shown side by side in the Ownership tab.
+    pub ownership_state: TableState,
+}
+
+impl AuthorSort {
+    pub fn next(self) -> Self {
+        match self {
+            AuthorSort::Commits => AuthorSort::Insertions,
+            AuthorSort::Insertions => AuthorSort::Deletions,
+            AuthorSort::Deletions => AuthorSort::Files,
+            AuthorSort::Files => AuthorSort::ActiveDays,
+            AuthorSort::ActiveDays => AuthorSort::Name,
+            AuthorSort::Name => AuthorSort::Commits,
+        }
+    }
+}
+
+impl TypeScopeMatrix {
+    /// Commits without a type or a scope are left out.
+    pub fn collect(commits: &[CommitInfo]) -> Self {
+        let pairs = commits
+            .iter()
+            .filter(|commit| !commit.type_.is_empty() && !commit.scope.is_empty())
+            .map(|commit| (commit.type_.as_str(), commit.scope.as_str()))
+            .collect::<Vec<(&str, &str)>>();
+
+        let by_total = |values: Vec<&str>| {
+            let mut totals: Vec<(String, usize)> = vec![];
+            for value in values {
+                match totals.iter_mut().find(|(name, _)| name == value) {
+                    Some(entry) => entry.1 += 1,
+                    None => totals.push((value.to_string(), 1)),
+                }
+            }
+            totals.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            totals
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<String>>()
+        };
+        let types = by_total(pairs.iter().map(|(type_, _)| *type_).collect());
+        let scopes = by_total(pairs.iter().map(|(_, scope)| *scope).collect());
+
+        let mut counts = vec![vec![0; types.len()]; scopes.len()];
+        for (type_, scope) in pairs {
+            let column = types.iter().position(|t| t == type_);
+            let row = scopes.iter().position(|s| s == scope);
+            if let (Some(row), Some(column)) = (row, column) {
+                counts[row][column] += 1;
+            }
+        }
+
+        TypeScopeMatrix {
+            types,
+            scopes,
+            counts,
+        }
+    }
+
+    pub fn max(&self) -> u64 {
+        self.counts.iter().flatten().copied().max().unwrap_or(0)
+    }
 }
 
 impl<'a> App<'a> {
+    pub fn new(
+        config: &'a Config,
+        bucket: &'a CommitBucket,
+        ownerships: Option<Ownerships<'a>>,
+    ) -> Self {
+        let mut app = App {
+            titles: vec![
+                "Stats",
+                "Files",
+                "Authors",
+                "Commits",
+                "Timeline",
+                "Ownership",
+            ],
+            config: Some(config),
+            all_commits: &bucket.commits,
+            ownerships,
+            sort_file_summs: config.sort_files.clone(),
+            sort_authors_order: SortType::Desc,
+            ..Default::default()
+        };
+        app.show(bucket.commits.clone(), &bucket.info);
+
+        app
+    }
+
+    /// Replaces the data of every tab with the given commits and their aggregation.
+    fn show(&mut self, commits: Vec<CommitInfo>, info: &BucketInfo) {
+        let Some(config) = self.config else {
+            return;
+        };
+
+        self.types = info
+            .types
+            .info
+            .iter()
+            .map(|(_type, info)| (_type.to_string(), (info.freq * 100.0).round() as u64))
+            .collect();
+
+        self.scopes = info
+            .scopes
+            .info
+            .iter()
+            .map(|(scope, info)| (scope.to_string(), (info.freq * 100.0).round() as u64))
+            .collect();
+        self.scopes
+            .sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        self.type_scopes = TypeScopeMatrix::collect(&commits);
+        self.timeline = Timeline::collect(&commits, self.timeline.period);
+
+        self.trailers = info
+            .trailers
+            .info
+            .iter()
+            .map(|(key, info)| (key.to_string(), info.count as u64))
+            .collect();
+        self.trailers
+            .sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        self.file_summs = map_file_summs(config, &info.file_summs)
+            .into_iter()
+            .cloned()
+            .collect();
+        self.authors = info.authors.clone();
+        self.commits = commits.into();
+
+        self.file_drill_down = None;
+        self.file_table_state = TableState::default().with_selected(Some(0));
+        self.author_table_state = TableState::default().with_selected(Some(0));
+        self.commit_table_state = TableState::default().with_selected(Some(0));
+        self.file_commit_table_state = TableState::default();
+        self.ownership_state = TableState::default().with_selected(Some(0));
+        self.sort_files();
+        self.sort_authors_table();
+    }
+
     pub fn tick(&self) {}
 
     pub fn quit(&mut self) {
         self.should_quit = true;
     }
 
-    pub fn increment_counter(&mut self) {
-        if let Some(res) = self.counter.checked_add(1) {
-            self.counter = res;
-        }
-    }
-
-    pub fn decrement_counter(&mut self) {
-        if let Some(res) = self.counter.checked_sub(1) {
-            self.counter = res;
-        }
+    pub fn toggle_help(&mut self) {
+        self.show_help = !self.show_help;
     }
 
     pub fn next_tab(&mut self) {
@@ -45,18 +229,190 @@ impl<'a> App<'a> {
         }
     }
 
-    pub fn toggle_sort_files(&mut self) {
-        if matches!(self.sort_file_summs, SortType::Asc) {
-            self.sort_file_summs = SortType::Desc;
+    pub fn active_title(&self) -> &str {
+        self.titles
+            .get(self.active_tab)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Toggles the order of the table in the active tab.
+    pub fn toggle_sort_order(&mut self) {
+        let order = match self.active_title() {
+            "Authors" => &mut self.sort_authors_order,
+            _ => &mut self.sort_file_summs,
+        };
+        *order = match order {
+            SortType::Asc => SortType::Desc,
+            SortType::Desc => SortType::Asc,
+        };
+        self.sort_files();
+        self.sort_authors_table();
+    }
+
+    /// Sorts the files table, kept on the app so the selection points at the rendered row.
+    pub fn sort_files(&mut self) {
+        let sort_file_summs = self.sort_file_summs.clone();
+        self.file_summs.sort_by(|a, b| match sort_file_summs {
+            SortType::Asc => a.total_changes.abs().cmp(&b.total_changes.abs()),
+            SortType::Desc => b.total_changes.abs().cmp(&a.total_changes.abs()),
+        });
+    }
+
+    /// Sorts the authors table by the `sort_authors` column, kept on the app like the files.
+    pub fn sort_authors_table(&mut self) {
+        let sort_authors = self.sort_authors;
+        let sort_authors_order = self.sort_authors_order.clone();
+        self.authors.sort_by(|a, b| {
+            let ordering = match sort_authors {
+                AuthorSort::Commits => a.commits.total_cmp(&b.commits),
+                AuthorSort::Insertions => a.insertions.total_cmp(&b.insertions),
+                AuthorSort::Deletions => a.deletions.total_cmp(&b.deletions),
+                AuthorSort::Files => a.files_touched.cmp(&b.files_touched),
+                AuthorSort::ActiveDays => a.active_days.cmp(&b.active_days),
+                AuthorSort::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            };
+            match sort_authors_order {
+                SortType::Asc => ordering,
+                SortType::Desc => ordering.reverse(),
+            }
+        });
+    }
+
+    pub fn selected_file(&self) -> Option<&FileStatInfo> {
+        self.file_table_state
+            .selected()
+            .and_then(|indx| self.file_summs.get(indx))
+    }
+
+    pub fn open_file_drill_down(&mut self) {
+        if self.active_title() != "Files" || self.file_drill_down.is_some() {
             return;
         }
-        self.sort_file_summs = SortType::Asc;
+        if let Some(file) = self.selected_file() {
+            self.file_drill_down = Some(file.path.clone());
+            self.file_commit_table_state.select(Some(0));
+        }
+    }
+
+    /// Closes the drill down, returns false when there was none.
+    pub fn close_file_drill_down(&mut self) -> bool {
+        self.file_drill_down.take().is_some()
+    }
+
+    /// Commits that changed the drilled down path, with the changes to that path.
+    pub fn file_commits(&self) -> Vec<(&CommitInfo, &FileStatInfo)> {
+        let Some(path) = &self.file_drill_down else {
+            return vec![];
+        };
+
+        self.commits
+            .iter()
+            .filter_map(|commit| {
+                let stats = commit.stats.as_ref()?;
+                stats
+                    .file_stat_infos
+                    .iter()
+                    .find(|file_stat_info| &file_stat_info.path == path)
+                    .map(|file_stat_info| (commit, file_stat_info))
+            })
+            .collect()
+    }
+
+    pub fn next_sort_authors(&mut self) {
+        self.sort_authors = self.sort_authors.next();
+        self.sort_authors_table();
+    }
+
+    /// Re-buckets the timeline by the next of day, week and month.
+    pub fn next_timeline_period(&mut self) {
+        self.timeline = Timeline::collect(&self.commits, self.timeline.period.next());
+    }
+
+    /// Times of the oldest and the newest shown commit.
+    pub fn date_span(&self) -> Option<(i64, i64)> {
+        let times = self.commits.iter().map(|commit| commit.time);
+        Some((times.clone().min()?, times.max()?))
+    }
+
+    pub fn selected_commit(&self) -> Option<&CommitInfo> {
+        self.commit_table_state
+            .selected()
+            .and_then(|indx| self.commits.get(indx))
+    }
+
+    pub fn open_filter(&mut self) {
+        self.filter_input = Some(self.filter.clone());
+    }
+
+    pub fn close_filter(&mut self) {
+        self.filter_input = None;
+        self.filter_error = None;
+    }
+
+    /// Applies the typed expression, the prompt stays open when it does not parse.
+    pub fn submit_filter(&mut self) {
+        let Some(input) = self.filter_input.clone() else {
+            return;
+        };
+        match CommitFilter::parse(&input) {
+            Ok(filter) => {
+                self.close_filter();
+                self.filter = input.trim().to_string();
+                self.apply_filter(&filter);
+            }
+            Err(e) => self.filter_error = Some(e.to_string()),
+        }
+    }
+
+    /// Re-runs the bucket aggregation over the loaded commits left by the filter.
+    pub fn apply_filter(&mut self, filter: &CommitFilter) {
+        let Some(config) = self.config else {
+            return;
+        };
+        let commits = filter.apply(self.all_commits);
+        let info = CommitBucket::collect_bucket_info(&commits, config);
+        let bucket = CommitBucket { commits, info };
+        match config
+            .ownerships
+            .as_deref()
+            .map(|groups| Ownerships::build(groups, &bucket, config))
+            .transpose()
+        {
+            Ok(ownerships) => self.ownerships = ownerships,
+            Err(e) => self.filter_error = Some(e.to_string()),
+        }
+        self.show(bucket.commits, &bucket.info);
+    }
+
+    /// State and row count of the table in the active tab.
+    fn active_table(&mut self) -> Option<(&mut TableState, usize)> {
+        match self.active_title() {
+            "Files" if self.file_drill_down.is_some() => {
+                let len = self.file_commits().len();
+                Some((&mut self.file_commit_table_state, len))
+            }
+            "Files" => Some((&mut self.file_table_state, self.file_summs.len())),
+            "Authors" => Some((&mut self.author_table_state, self.authors.len())),
+            "Commits" => Some((&mut self.commit_table_state, self.commits.len())),
+            "Ownership" => {
+                let len = self
+                    .ownerships
+                    .as_ref()
+                    .map_or(0, |ownerships| ownerships.ow_buckets.len());
+                Some((&mut self.ownership_state, len))
+            }
+            _ => None,
+        }
     }
 
     pub fn table_next_item(&mut self) {
-        let i = match self.file_table_state.selected() {
+        let Some((state, len)) = self.active_table().filter(|(_, len)| *len > 0) else {
+            return;
+        };
+        let i = match state.selected() {
             Some(i) => {
-                if i >= self.file_summs.len() - 1 {
+                if i >= len - 1 {
                     0
                 } else {
                     i + 1
@@ -65,14 +421,17 @@ impl<'a> App<'a> {
             None => 0,
         };
 
-        self.file_table_state.select(Some(i));
+        state.select(Some(i));
     }
 
     pub fn table_prev_item(&mut self) {
-        let i = match self.file_table_state.selected() {
+        let Some((state, len)) = self.active_table().filter(|(_, len)| *len > 0) else {
+            return;
+        };
+        let i = match state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.file_summs.len() - 1
+                    len - 1
                 } else {
                     i - 1
                 }
@@ -80,24 +439,230 @@ impl<'a> App<'a> {
             None => 0,
         };
 
-        self.file_table_state.select(Some(i));
+        state.select(Some(i));
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::commit::Stats;
+    use crate::test_utils::{mock_config, mock_repo_config, setup_repo};
+    use tempfile::TempDir;
+    #[test]
+    fn can_move_in_active_table() {
+        let mut app = App {
+            titles: vec!["Stats", "Files", "Authors"],
+            authors: vec![AuthorStats::default(), AuthorStats::default()],
+            ..Default::default()
+        };
+
+        app.table_next_item();
+        assert_eq!(app.author_table_state.selected(), None);
+
+        app.active_tab = 2;
+        app.table_next_item();
+        app.table_next_item();
+        assert_eq!(app.author_table_state.selected(), Some(1));
+        app.table_next_item();
+        assert_eq!(app.author_table_state.selected(), Some(0));
+        app.table_prev_item();
+        assert_eq!(app.author_table_state.selected(), Some(1));
+        assert_eq!(app.file_table_state.selected(), None);
+
+        app.toggle_sort_order();
+        assert!(matches!(app.sort_authors_order, SortType::Asc));
+        assert!(matches!(app.sort_file_summs, SortType::Desc));
+    }
+
+    #[test]
+    fn can_sort_authors() {
+        let author = |name: &str, commits: f64| AuthorStats {
+            name: name.to_string(),
+            commits,
+            ..Default::default()
+        };
+        let mut app = App {
+            titles: vec!["Stats", "Files", "Authors"],
+            active_tab: 2,
+            sort_authors_order: SortType::Desc,
+            authors: vec![author("bob", 1.0), author("alice", 3.0)],
+            ..Default::default()
+        };
+        let names = |app: &App| {
+            app.authors
+                .iter()
+                .map(|a| a.name.clone())
+                .collect::<Vec<String>>()
+        };
+
+        app.sort_authors_table();
+        assert_eq!(names(&app), vec!["alice", "bob"]);
+        app.toggle_sort_order();
+        assert_eq!(names(&app), vec!["bob", "alice"]);
+    }
+
+    #[test]
+    fn can_drill_down_into_file() {
+        let file = |path: &str, inserted: usize| FileStatInfo {
+            path: path.to_string(),
+            inserted,
+            deleted: 0,
+            total_changes: inserted as i64,
+        };
+        let commit = |id: &str, files: Vec<FileStatInfo>| CommitInfo {
+            id: id.to_string(),
+            stats: Some(Stats {
+                file_stat_infos: files,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let commits = [
+            commit("c2", vec![file("src/a.rs", 1), file("src/b.rs", 5)]),
+            commit("c1", vec![file("src/a.rs", 2)]),
+        ];
+        let mut app = App {
+            titles: vec!["Stats", "Files"],
+            active_tab: 1,
+            file_summs: vec![file("src/a.rs", 3), file("src/b.rs", 5)],
+            commits: commits.into(),
+            file_table_state: TableState::default().with_selected(Some(0)),
+            ..Default::default()
+        };
+
+        app.sort_files();
+        assert_eq!(app.selected_file().unwrap().path, "src/b.rs");
+
+        app.table_next_item();
+        app.open_file_drill_down();
+        assert_eq!(app.file_drill_down.as_deref(), Some("src/a.rs"));
+        let file_commits = app.file_commits();
+        assert_eq!(file_commits.len(), 2);
+        assert_eq!(file_commits[1].0.id, "c1");
+        assert_eq!(file_commits[1].1.inserted, 2);
+
+        app.table_next_item();
+        assert_eq!(app.file_commit_table_state.selected(), Some(1));
+        assert_eq!(app.file_table_state.selected(), Some(1));
+
+        assert!(app.close_file_drill_down());
+        assert!(!app.close_file_drill_down());
+    }
+
+    #[test]
+    fn can_filter_loaded_commits() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo, example_commit_message) = setup_repo(&temp_dir);
+        let config = mock_repo_config(&temp_dir, None);
+        let bucket = CommitBucket::build(&repo, example_commit_message.as_str(), &config)
+            .expect("Failed to build commit bucket");
+        let mut app = App::new(&config, &bucket, None);
+        assert_eq!(app.commits.len(), 5);
+
+        app.open_filter();
+        app.filter_input = Some("type:colour".to_string());
+        app.submit_filter();
+        assert_eq!(app.commits.len(), 0);
+        assert!(app.types.is_empty() && app.authors.is_empty());
+
+        app.open_filter();
+        app.filter_input = Some("type:feat type:test".to_string());
+        app.submit_filter();
+        assert!(app.filter_input.is_none());
+        assert_eq!(app.filter, "type:feat type:test");
+        assert_eq!(app.commits.len(), 4);
+        assert_eq!(app.types.len(), 2);
+
+        app.open_filter();
+        assert_eq!(app.filter_input.as_deref(), Some("type:feat type:test"));
+        app.filter_input = Some("colour:red".to_string());
+        app.submit_filter();
+        assert!(app.filter_input.is_some() && app.filter_error.is_some());
+        app.close_filter();
+        assert_eq!(app.commits.len(), 4);
+    }
+
     #[test]
-    fn test_increment_counter() {
-        let mut app = App::default();
-        app.increment_counter();
-        assert_eq!(app.counter, 1);
+    fn can_rebuild_ownerships_on_filter() {
+        let commit = |type_: &str, paths: &[&str]| CommitInfo {
+            type_: type_.to_string(),
+            stats: Some(Stats {
+                file_stat_infos: paths
+                    .iter()
+                    .map(|path| FileStatInfo {
+                        path: path.to_string(),
+                        inserted: 1,
+                        deleted: 0,
+                        total_changes: 1,
+                    })
+                    .collect(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut config = mock_config(Some(vec![
+            "",
+            "--ownership",
+            "api=^api/",
+            "web=^web/",
+            "docs=^docs/",
+            "ops=^ops/",
+        ]));
+        config.merge_ownership_args().unwrap();
+        let commits = vec![
+            commit("feat", &["api/a.rs", "web/b.ts"]),
+            commit("fix", &["api/a.rs"]),
+            commit("docs", &["docs/c.md"]),
+        ];
+        let info = CommitBucket::collect_bucket_info(&commits, &config);
+        let bucket = CommitBucket { commits, info };
+        let groups = config.ownerships.as_deref().unwrap();
+        let ownerships = Ownerships::build(groups, &bucket, &config).unwrap();
+        let mut app = App::new(&config, &bucket, Some(ownerships));
+
+        let totals = |app: &App| {
+            app.ownerships
+                .as_ref()
+                .unwrap()
+                .ow_buckets
+                .iter()
+                .map(|ow_bucket| ow_bucket.cm_bucket.info.total)
+                .collect::<Vec<usize>>()
+        };
+        assert_eq!(totals(&app), vec![2, 1, 1, 0]);
+
+        app.active_tab = 5;
+        app.table_next_item();
+        app.table_next_item();
+        assert_eq!(app.ownership_state.selected(), Some(2));
+
+        app.filter_input = Some("type:fix".to_string());
+        app.submit_filter();
+        assert_eq!(totals(&app), vec![1, 0, 0, 0]);
+        assert_eq!(app.ownership_state.selected(), Some(0));
     }
 
     #[test]
-    fn test_decrement_counter() {
-        let mut app = App::default();
-        app.decrement_counter();
-        assert_eq!(app.counter, 0);
+    fn can_collect_type_scope_matrix() {
+        let commit = |type_: &str, scope: &str| CommitInfo {
+            type_: type_.to_string(),
+            scope: scope.to_string(),
+            ..Default::default()
+        };
+        let commits = [
+            commit("fix", "ui"),
+            commit("feat", "api"),
+            commit("fix", "api"),
+            commit("fix", "api"),
+            commit("feat", ""),
+            commit("", "ui"),
+        ];
+
+        let matrix = TypeScopeMatrix::collect(&commits);
+        assert_eq!(matrix.types, vec!["fix", "feat"]);
+        assert_eq!(matrix.scopes, vec!["api", "ui"]);
+        assert_eq!(matrix.counts, vec![vec![2, 1], vec![1, 0]]);
+        assert_eq!(matrix.max(), 2);
     }
 }
@@ -0,0 +1,94 @@
+use std::marker::PhantomData;
+
+use super::Component;
+use crate::{
+    authors::TopEntry,
+    config::SortType,
+    utils::format_weight,
+    window::app::{App, AuthorSort},
+};
+use ratatui::{
+    layout::{Alignment, Constraint},
+    style::{Color, Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+
+pub struct AuthorsTable<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+const COLUMNS: [(&str, Option<AuthorSort>); 8] = [
+    ("author", Some(AuthorSort::Name)),
+    ("commits", Some(AuthorSort::Commits)),
+    ("insertion", Some(AuthorSort::Insertions)),
+    ("deletion", Some(AuthorSort::Deletions)),
+    ("files", Some(AuthorSort::Files)),
+    ("active days", Some(AuthorSort::ActiveDays)),
+    ("top types", None),
+    ("top scopes", None),
+];
+
+impl<'a> Component<'a> for AuthorsTable<'a> {
+    type Output = Table<'a>;
+    fn new(app: &App) -> Self::Output {
+        let (first, rest) = "authors, `s` sort column, `f` sort order".split_at(1);
+        let title = Line::from(vec![first.light_red(), rest.light_blue()]);
+
+        let rows: Vec<Row<'_>> = app
+            .authors
+            .iter()
+            .map(|a| {
+                Row::new(vec![
+                    Cell::new(a.name.to_string()).style(Style::default().fg(Color::White)),
+                    Cell::new(format_weight(a.commits))
+                        .style(Style::default().add_modifier(Modifier::BOLD)),
+                    Cell::new(format!("{}+", format_weight(a.insertions.round())))
+                        .style(Style::default().fg(Color::Green)),
+                    Cell::new(format!("{}-", format_weight(a.deletions.round())))
+                        .style(Style::default().fg(Color::Red)),
+                    Cell::new(a.files_touched.to_string()),
+                    Cell::new(a.active_days.to_string()),
+                    Cell::new(TopEntry::join(&a.top_types)),
+                    Cell::new(TopEntry::join(&a.top_scopes)),
+                ])
+            })
+            .collect();
+
+        let header = COLUMNS
+            .iter()
+            .map(|(column, sort)| match sort {
+                Some(sort) if *sort == app.sort_authors => match app.sort_authors_order {
+                    SortType::Asc => format!("{} ▲", column),
+                    SortType::Desc => format!("{} ▼", column),
+                },
+                _ => column.to_string(),
+            })
+            .collect::<Vec<String>>();
+
+        Table::new(
+            rows,
+            [
+                Constraint::Percentage(20),
+                Constraint::Percentage(9),
+                Constraint::Percentage(9),
+                Constraint::Percentage(9),
+                Constraint::Percentage(7),
+                Constraint::Percentage(10),
+                Constraint::Percentage(18),
+                Constraint::Percentage(18),
+            ],
+        )
+        .column_spacing(1)
+        .style(Style::new().fg(Color::Yellow))
+        .header(Row::new(header).style(Style::new().bold()).bottom_margin(1))
+        .highlight_style(Style::new().reversed())
+        .block(
+            Block::default()
+                .fg(Color::Yellow)
+                .borders(Borders::ALL)
+                .title(title)
+                .title_alignment(Alignment::Center),
+        )
+    }
+}
@@ -15,7 +15,7 @@ pub struct Chart<'a> {
 }
 impl<'a> Component<'a> for Chart<'a> {
     type Output = BarChart<'a>;
-    fn new(app: &'a mut App) -> Self::Output {
+    fn new(app: &'a App) -> Self::Output {
         BarChart::default()
             .block(
                 Block::default()
@@ -0,0 +1,192 @@
+use std::marker::PhantomData;
+
+use super::Component;
+use crate::window::app::App;
+use chrono::DateTime;
+use ratatui::{
+    layout::{Alignment, Constraint},
+    style::{Color, Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+};
+
+pub struct CommitsTable<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+pub struct CommitMessage<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+pub struct CommitFilesTable<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+impl<'a> Component<'a> for CommitsTable<'a> {
+    type Output = Table<'a>;
+    fn new(app: &App) -> Self::Output {
+        let (first, rest) = "commits".split_at(1);
+        let title = Line::from(vec![first.light_red(), rest.light_blue()]);
+
+        let rows: Vec<Row<'_>> = app
+            .commits
+            .iter()
+            .map(|c| {
+                let (insertions, deletions) = c
+                    .stats
+                    .as_ref()
+                    .map_or((0, 0), |stats| (stats.insertions, stats.deletions));
+                Row::new(vec![
+                    Cell::new(c.id.chars().take(7).collect::<String>())
+                        .style(Style::default().fg(Color::LightYellow)),
+                    Cell::new(format_day(c.time)),
+                    Cell::new(c.author.name.to_string()).style(Style::default().fg(Color::White)),
+                    Cell::new(c.type_.to_string()).style(Style::default().fg(Color::Cyan)),
+                    Cell::new(c.scope.to_string()).style(Style::default().fg(Color::Magenta)),
+                    Cell::new(c.summary.to_string()).style(Style::default().fg(Color::White)),
+                    Cell::new(format!("{}+", insertions)).style(Style::default().fg(Color::Green)),
+                    Cell::new(format!("{}-", deletions)).style(Style::default().fg(Color::Red)),
+                ])
+            })
+            .collect();
+
+        Table::new(
+            rows,
+            [
+                Constraint::Length(7),
+                Constraint::Length(10),
+                Constraint::Percentage(12),
+                Constraint::Percentage(7),
+                Constraint::Percentage(9),
+                Constraint::Percentage(42),
+                Constraint::Percentage(7),
+                Constraint::Percentage(7),
+            ],
+        )
+        .column_spacing(1)
+        .style(Style::new().fg(Color::Yellow))
+        .header(
+            Row::new(vec![
+                "hash",
+                "date",
+                "author",
+                "type",
+                "scope",
+                "summary",
+                "insertion",
+                "deletion",
+            ])
+            .style(Style::new().bold())
+            .bottom_margin(1),
+        )
+        .highlight_style(Style::new().reversed())
+        .block(
+            Block::default()
+                .fg(Color::Yellow)
+                .borders(Borders::ALL)
+                .title(title)
+                .title_alignment(Alignment::Center),
+        )
+    }
+}
+
+impl<'a> Component<'a> for CommitMessage<'a> {
+    type Output = Paragraph<'a>;
+    fn new(app: &App) -> Self::Output {
+        let lines =
+            match app.selected_commit() {
+                Some(commit) => {
+                    let mut lines = vec![
+                        Line::styled(
+                            format!("commit {}", commit.id),
+                            Style::default().fg(Color::LightYellow),
+                        ),
+                        Line::from(format!(
+                            "Author: {} <{}>",
+                            commit.author.name, commit.author.email
+                        )),
+                    ];
+                    lines.extend(commit.co_authors.iter().map(|co_author| {
+                        Line::from(format!(
+                            "Co-author: {} <{}>",
+                            co_author.name, co_author.email
+                        ))
+                    }));
+                    lines.push(Line::from(format!("Date: {}", format_day(commit.time))));
+                    lines.push(Line::from(""));
+                    lines.extend(commit.message().lines().map(|line| {
+                        Line::styled(line.to_string(), Style::default().fg(Color::White))
+                    }));
+                    lines
+                }
+                None => vec![Line::from("no commit selected")],
+            };
+
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .style(Style::default().fg(Color::LightYellow))
+            .block(
+                Block::default()
+                    .title("message")
+                    .title_alignment(Alignment::Center)
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::Yellow)),
+            )
+    }
+}
+
+impl<'a> Component<'a> for CommitFilesTable<'a> {
+    type Output = Table<'a>;
+    fn new(app: &App) -> Self::Output {
+        let rows: Vec<Row<'_>> = app
+            .selected_commit()
+            .and_then(|commit| commit.stats.as_ref())
+            .map(|stats| {
+                stats
+                    .file_stat_infos
+                    .iter()
+                    .map(|f| {
+                        Row::new(vec![
+                            Cell::new(f.path.to_string()).style(Style::default().fg(Color::White)),
+                            Cell::new(f.total_changes.to_string())
+                                .style(Style::default().add_modifier(Modifier::BOLD)),
+                            Cell::new(format!("{}+", f.inserted))
+                                .style(Style::default().fg(Color::Green)),
+                            Cell::new(format!("{}-", f.deleted))
+                                .style(Style::default().fg(Color::Red)),
+                        ])
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Table::new(
+            rows,
+            [
+                Constraint::Percentage(52),
+                Constraint::Percentage(16),
+                Constraint::Percentage(16),
+                Constraint::Percentage(16),
+            ],
+        )
+        .column_spacing(1)
+        .style(Style::new().fg(Color::Yellow))
+        .header(
+            Row::new(vec!["path", "changes", "insertion", "deletion"])
+                .style(Style::new().bold())
+                .bottom_margin(1),
+        )
+        .block(
+            Block::default()
+                .fg(Color::Yellow)
+                .borders(Borders::ALL)
+                .title("changed files")
+                .title_alignment(Alignment::Center),
+        )
+    }
+}
+
+fn format_day(seconds: i64) -> String {
+    DateTime::from_timestamp(seconds, 0)
+        .map_or("-".to_string(), |time| time.format("%Y-%m-%d").to_string())
+}
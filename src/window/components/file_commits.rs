@@ -0,0 +1,150 @@
+use std::marker::PhantomData;
+
+use super::Component;
+use crate::window::app::App;
+use chrono::DateTime;
+use ratatui::{
+    layout::{Alignment, Constraint},
+    style::{Color, Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, Borders, Cell, Row, Table},
+};
+
+/// Commits that changed the drilled down path of the files table.
+pub struct FileCommitsTable<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+/// Authors and co-authors of the commits that changed the drilled down path.
+pub struct FileAuthorsTable<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+impl<'a> Component<'a> for FileCommitsTable<'a> {
+    type Output = Table<'a>;
+    fn new(app: &App) -> Self::Output {
+        let path = app.file_drill_down.clone().unwrap_or_default();
+        let (first, rest) = "commits of ".split_at(1);
+        let title = Line::from(vec![
+            first.light_red(),
+            rest.light_blue(),
+            path.white(),
+            ", `Esc` back".light_blue(),
+        ]);
+
+        let rows: Vec<Row<'_>> = app
+            .file_commits()
+            .iter()
+            .map(|(c, f)| {
+                let authors = c
+                    .participants()
+                    .iter()
+                    .map(|author| author.name.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(", ");
+                Row::new(vec![
+                    Cell::new(c.id.chars().take(7).collect::<String>())
+                        .style(Style::default().fg(Color::LightYellow)),
+                    Cell::new(
+                        DateTime::from_timestamp(c.time, 0)
+                            .map_or("-".to_string(), |time| time.format("%Y-%m-%d").to_string()),
+                    ),
+                    Cell::new(authors).style(Style::default().fg(Color::White)),
+                    Cell::new(c.summary.to_string()).style(Style::default().fg(Color::White)),
+                    Cell::new(f.total_changes.to_string())
+                        .style(Style::default().add_modifier(Modifier::BOLD)),
+                    Cell::new(format!("{}+", f.inserted)).style(Style::default().fg(Color::Green)),
+                    Cell::new(format!("{}-", f.deleted)).style(Style::default().fg(Color::Red)),
+                ])
+            })
+            .collect();
+
+        Table::new(
+            rows,
+            [
+                Constraint::Length(7),
+                Constraint::Length(10),
+                Constraint::Percentage(18),
+                Constraint::Percentage(34),
+                Constraint::Percentage(8),
+                Constraint::Percentage(10),
+                Constraint::Percentage(10),
+            ],
+        )
+        .column_spacing(1)
+        .style(Style::new().fg(Color::Yellow))
+        .header(
+            Row::new(vec![
+                "hash", "date", "authors", "summary", "changes", "ins", "del",
+            ])
+            .style(Style::new().bold())
+            .bottom_margin(1),
+        )
+        .highlight_style(Style::new().reversed())
+        .block(
+            Block::default()
+                .fg(Color::Yellow)
+                .borders(Borders::ALL)
+                .title(title)
+                .title_alignment(Alignment::Center),
+        )
+    }
+}
+
+impl<'a> Component<'a> for FileAuthorsTable<'a> {
+    type Output = Table<'a>;
+    fn new(app: &App) -> Self::Output {
+        // name, commits, inserted, deleted
+        let mut authors: Vec<(String, usize, usize, usize)> = vec![];
+        for (commit, file) in app.file_commits() {
+            for author in commit.participants() {
+                match authors.iter_mut().find(|(name, ..)| *name == author.name) {
+                    Some(entry) => {
+                        entry.1 += 1;
+                        entry.2 += file.inserted;
+                        entry.3 += file.deleted;
+                    }
+                    None => authors.push((author.name.clone(), 1, file.inserted, file.deleted)),
+                }
+            }
+        }
+        authors.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let rows: Vec<Row<'_>> = authors
+            .into_iter()
+            .map(|(name, commits, inserted, deleted)| {
+                Row::new(vec![
+                    Cell::new(name).style(Style::default().fg(Color::White)),
+                    Cell::new(commits.to_string())
+                        .style(Style::default().add_modifier(Modifier::BOLD)),
+                    Cell::new(format!("{}+", inserted)).style(Style::default().fg(Color::Green)),
+                    Cell::new(format!("{}-", deleted)).style(Style::default().fg(Color::Red)),
+                ])
+            })
+            .collect();
+
+        Table::new(
+            rows,
+            [
+                Constraint::Percentage(40),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
+                Constraint::Percentage(20),
+            ],
+        )
+        .column_spacing(1)
+        .style(Style::new().fg(Color::Yellow))
+        .header(
+            Row::new(vec!["author", "commits", "ins", "del"])
+                .style(Style::new().bold())
+                .bottom_margin(1),
+        )
+        .block(
+            Block::default()
+                .fg(Color::Yellow)
+                .borders(Borders::ALL)
+                .title("authors involved")
+                .title_alignment(Alignment::Center),
+        )
+    }
+}
@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use super::Component;
-use crate::{config::SortType, window::app::App};
+use crate::window::app::App;
 use ratatui::{
     layout::{Alignment, Constraint},
     style::{Color, Modifier, Style, Stylize},
@@ -15,13 +15,8 @@ pub struct FilesTable<'a> {
 
 impl<'a> Component<'a> for FilesTable<'a> {
     type Output = Table<'a>;
-    fn new(app: &mut App) -> Self::Output {
-        app.file_summs.sort_by(|a, b| match app.sort_file_summs {
-            SortType::Asc => a.total_changes.abs().cmp(&b.total_changes.abs()),
-            SortType::Desc => b.total_changes.abs().cmp(&a.total_changes.abs()),
-        });
-
-        let (first, rest) = "file diff summary".split_at(1);
+    fn new(app: &App) -> Self::Output {
+        let (first, rest) = "file diff summary, `Enter` commits of the file".split_at(1);
         let title = Line::from(vec![first.light_red(), rest.light_blue()]);
 
         let rows: Vec<Row<'_>> = app
@@ -32,10 +27,8 @@ impl<'a> Component<'a> for FilesTable<'a> {
                     Cell::new(f.path.to_string()).style(Style::default().fg(Color::White)),
                     Cell::new(f.total_changes.to_string())
                         .style(Style::default().add_modifier(Modifier::BOLD)),
-                    Cell::new(format!("{}+", f.inserted.to_string()))
-                        .style(Style::default().fg(Color::Green)),
-                    Cell::new(format!("{}-", f.deleted.to_string()))
-                        .style(Style::default().fg(Color::Red)),
+                    Cell::new(format!("{}+", f.inserted)).style(Style::default().fg(Color::Green)),
+                    Cell::new(format!("{}-", f.deleted)).style(Style::default().fg(Color::Red)),
                 ])
             })
             .collect();
@@ -0,0 +1,55 @@
+use std::marker::PhantomData;
+
+use super::Component;
+use crate::window::app::App;
+use ratatui::{
+    layout::Alignment,
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Prompt of the commit filter, or the applied filter once it is closed.
+pub struct FilterBar<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+impl<'a> Component<'a> for FilterBar<'a> {
+    type Output = Paragraph<'a>;
+    fn new(app: &App) -> Self::Output {
+        let count = format!("{} of {} commits", app.commits.len(), app.all_commits.len());
+
+        let (title, line) = match (&app.filter_input, &app.filter_error) {
+            (Some(_), Some(error)) => (
+                "filter, `Enter` apply, `Esc` cancel".to_string(),
+                Line::styled(error.to_string(), Style::default().fg(Color::Red)),
+            ),
+            (Some(input), None) => (
+                "filter, `Enter` apply, `Esc` cancel".to_string(),
+                Line::from(vec![
+                    Span::styled("/", Style::default().fg(Color::LightRed)),
+                    Span::styled(input.to_string(), Style::default().fg(Color::White)),
+                    Span::styled("█", Style::default().fg(Color::White)),
+                ]),
+            ),
+            (None, _) => (
+                format!("filter, {}, `/` edit", count),
+                Line::styled(app.filter.to_string(), Style::default().fg(Color::White)),
+            ),
+        };
+
+        let (first, rest) = title.split_at(1);
+        let title = Line::from(vec![
+            first.to_string().light_red(),
+            rest.to_string().light_blue(),
+        ]);
+
+        Paragraph::new(line).block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .style(Style::default().fg(Color::Yellow)),
+        )
+    }
+}
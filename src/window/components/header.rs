@@ -1,34 +1,99 @@
-use crate::window::app::App;
-use std::marker::PhantomData;
+use crate::{repo::RevisionSelection, window::app::App};
+use chrono::DateTime;
+use std::{env::current_dir, marker::PhantomData};
 
 use ratatui::{
     layout::Alignment,
-    style::{Color, Style},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
     widgets::{Block, BorderType, Borders, Paragraph},
 };
 
 use super::Component;
 
+/// Analysed repository, revisions, filters and the commits they leave.
 pub struct Header<'a> {
     marker: PhantomData<&'a ()>,
 }
+
 impl<'a> Component<'a> for Header<'a> {
     type Output = Paragraph<'a>;
-    fn new(_app: &mut App) -> Self::Output {
-        Paragraph::new(format!(
-            "
-        Press `Esc`, `Ctrl-C`, or `q` to stop running.\n\
-        Press `j` and `k` to increment and decrement the counter respectively.\n\
-    ",
-        ))
-        .block(
-            Block::default()
-                .title("Counter App")
-                .title_alignment(Alignment::Center)
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded),
-        )
-        .style(Style::default().fg(Color::LightYellow))
-        .alignment(Alignment::Center)
+    fn new(app: &App) -> Self::Output {
+        let path = app
+            .config
+            .and_then(|config| config.path.clone())
+            .or(current_dir()
+                .ok()
+                .map(|dir| dir.to_string_lossy().to_string()))
+            .unwrap_or_default();
+        let revisions = app.config.map_or("HEAD".to_string(), |config| {
+            RevisionSelection::from(config).describe()
+        });
+
+        let mut filters = app
+            .config
+            .map(|config| config.active_filters())
+            .unwrap_or_default();
+        if !app.filter.is_empty() {
+            filters.push(format!("tui: {}", app.filter));
+        }
+        let filters = match filters.is_empty() {
+            true => "none".to_string(),
+            false => filters.join("; "),
+        };
+
+        let span = match app.date_span() {
+            Some((first, last)) => {
+                let days = (last - first) / 86_400 + 1;
+                format!(
+                    "{} .. {} ({} days)",
+                    format_day(first),
+                    format_day(last),
+                    days
+                )
+            }
+            None => "-".to_string(),
+        };
+
+        let field =
+            |name: &str| Span::styled(format!("{}: ", name), Style::default().fg(Color::LightBlue));
+        let value = |value: String| Span::styled(value, Style::default().fg(Color::White));
+        let lines = vec![
+            Line::from(vec![
+                field("repository"),
+                value(path),
+                Span::raw("   "),
+                field("revisions"),
+                value(revisions),
+            ]),
+            Line::from(vec![
+                field("commits"),
+                value(format!(
+                    "{} of {}",
+                    app.commits.len(),
+                    app.all_commits.len()
+                )),
+                Span::raw("   "),
+                field("span"),
+                value(span),
+            ]),
+            Line::from(vec![field("filters"), value(filters)]),
+        ];
+
+        let (first, rest) = "git-lyze, `?` help".split_at(1);
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(Line::from(vec![first.light_red(), rest.light_blue()]))
+                    .title_alignment(Alignment::Center)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .style(Style::default().fg(Color::LightYellow))
     }
 }
+
+fn format_day(seconds: i64) -> String {
+    DateTime::from_timestamp(seconds, 0)
+        .map_or("-".to_string(), |time| time.format("%Y-%m-%d").to_string())
+}
@@ -0,0 +1,40 @@
+use std::marker::PhantomData;
+
+use super::Component;
+use crate::window::{app::App, update::KEYBINDINGS};
+use ratatui::{
+    layout::{Alignment, Constraint},
+    style::{Color, Style, Stylize},
+    widgets::{Block, BorderType, Borders, Cell, Row, Table},
+};
+
+/// Keybindings overlay opened with `?`.
+pub struct Help<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+impl<'a> Component<'a> for Help<'a> {
+    type Output = Table<'a>;
+    fn new(_app: &App) -> Self::Output {
+        let rows = KEYBINDINGS.iter().map(|(key, action)| {
+            Row::new(vec![
+                Cell::new(*key).style(Style::default().fg(Color::LightRed)),
+                Cell::new(*action).style(Style::default().fg(Color::White)),
+            ])
+        });
+
+        Table::new(
+            rows,
+            [Constraint::Percentage(22), Constraint::Percentage(78)],
+        )
+        .column_spacing(2)
+        .block(
+            Block::default()
+                .title("keybindings, any key to close".light_blue())
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(Color::Yellow).bg(Color::Black)),
+        )
+    }
+}
@@ -1,11 +1,19 @@
 use crate::window::app::App;
 use ratatui::widgets::Widget;
+pub mod authors;
 pub mod chart;
-pub mod counter_paragraph;
+pub mod commits;
+pub mod file_commits;
 pub mod files;
+pub mod filter_bar;
 pub mod header;
+pub mod help;
+pub mod ownership;
+pub mod scopes;
+pub mod timeline;
+pub mod trailers;
 
 pub trait Component<'a> {
     type Output: Widget;
-    fn new(_app: &'a mut App) -> Self::Output;
+    fn new(_app: &'a App) -> Self::Output;
 }
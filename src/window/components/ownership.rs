@@ -0,0 +1,231 @@
+use std::marker::PhantomData;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    text::Line,
+    widgets::{BarChart, Block, Borders, Cell, Paragraph, Row, Table, Widget, Wrap},
+};
+
+use super::Component;
+use crate::{
+    commit::{FileStatInfo, Freq},
+    ownerships::OwnershipBucket,
+    window::app::App,
+};
+
+/// Ownership groups side by side, scrolled with `j`/`k`.
+pub struct OwnershipGroups<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+/// Groups shown at once, the others are reached by scrolling.
+const VISIBLE_GROUPS: usize = 3;
+
+/// Files listed per group, the busiest ones.
+const GROUP_FILES: usize = 10;
+
+/// Summary, types, scopes and busiest files of one group.
+struct GroupColumn {
+    name: String,
+    summary: Vec<Line<'static>>,
+    types: Vec<(String, u64)>,
+    scopes: Vec<(String, u64)>,
+    files: Vec<FileStatInfo>,
+}
+
+pub struct GroupColumns<'a> {
+    block: Block<'a>,
+    columns: Vec<GroupColumn>,
+}
+
+impl<'a> Component<'a> for OwnershipGroups<'a> {
+    type Output = GroupColumns<'a>;
+    fn new(app: &App) -> Self::Output {
+        let buckets = app
+            .ownerships
+            .as_ref()
+            .map(|ownerships| ownerships.ow_buckets.as_slice())
+            .unwrap_or_default();
+        let first = app
+            .ownership_state
+            .selected()
+            .unwrap_or(0)
+            .min(buckets.len().saturating_sub(1));
+        let shown = &buckets[first..buckets.len().min(first + VISIBLE_GROUPS)];
+
+        let title = match buckets.len() {
+            0 => "ownership groups".to_string(),
+            len => format!(
+                "ownership groups {}-{} of {}, `j`/`k` scroll",
+                first + 1,
+                first + shown.len(),
+                len
+            ),
+        };
+        let (head, rest) = title.split_at(1);
+
+        GroupColumns {
+            block: Block::default()
+                .fg(Color::Yellow)
+                .borders(Borders::ALL)
+                .title(Line::from(vec![
+                    head.to_string().light_red(),
+                    rest.to_string().light_blue(),
+                ]))
+                .title_alignment(Alignment::Center),
+            columns: shown.iter().map(GroupColumn::from).collect(),
+        }
+    }
+}
+
+impl From<&OwnershipBucket<'_>> for GroupColumn {
+    fn from(ow_bucket: &OwnershipBucket) -> Self {
+        let info = &ow_bucket.cm_bucket.info;
+        let (insertions, deletions) = info.file_summs.values().fold((0, 0), |sum, file| {
+            (sum.0 + file.inserted, sum.1 + file.deleted)
+        });
+
+        let mut summary = vec![
+            Line::from(vec![
+                info.total.to_string().bold(),
+                " commits, ".into(),
+                ow_bucket.shared_commits.to_string().bold(),
+                " also change other groups".into(),
+            ]),
+            Line::from(vec![
+                info.file_summs.len().to_string().bold(),
+                " files, ".into(),
+                format!("{}+", insertions).green(),
+                " ".into(),
+                format!("{}-", deletions).red(),
+            ]),
+        ];
+        if let Some(contributions) = &ow_bucket.contributions {
+            summary.push(Line::from(vec![
+                "in team ".into(),
+                contributions.in_team.commits.to_string().bold(),
+                ", out of team ".into(),
+                contributions.out_of_team.commits.to_string().bold(),
+                " commits".into(),
+            ]));
+        }
+
+        let percentages = |freq: &Freq| {
+            let mut values = freq
+                .info
+                .iter()
+                .map(|(name, info)| (name.to_string(), (info.freq * 100.0).round() as u64))
+                .collect::<Vec<(String, u64)>>();
+            values.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            values
+        };
+
+        let mut files = info.file_summs.values().cloned().collect::<Vec<_>>();
+        files.sort_by(|a, b| {
+            b.total_changes
+                .abs()
+                .cmp(&a.total_changes.abs())
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        files.truncate(GROUP_FILES);
+
+        GroupColumn {
+            name: ow_bucket.config.name.clone(),
+            summary,
+            types: percentages(&info.types),
+            scopes: percentages(&info.scopes),
+            files,
+        }
+    }
+}
+
+impl GroupColumn {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(self.name.bold().light_blue())
+            .title_alignment(Alignment::Center);
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(self.summary.len() as u16),
+                Constraint::Percentage(30),
+                Constraint::Percentage(30),
+                Constraint::Min(4),
+            ])
+            .split(inner);
+
+        Paragraph::new(self.summary)
+            .style(Style::default().fg(Color::White))
+            .wrap(Wrap { trim: true })
+            .render(layout[0], buf);
+        bar_chart("types", &self.types, Color::Cyan).render(layout[1], buf);
+        bar_chart("scopes", &self.scopes, Color::Magenta).render(layout[2], buf);
+
+        let rows = self.files.iter().map(|file| {
+            Row::new(vec![
+                Cell::new(file.path.to_string()).style(Style::default().fg(Color::White)),
+                Cell::new(file.total_changes.to_string())
+                    .style(Style::default().add_modifier(Modifier::BOLD)),
+            ])
+        });
+        Table::new(
+            rows,
+            [Constraint::Percentage(80), Constraint::Percentage(20)],
+        )
+        .column_spacing(1)
+        .header(Row::new(vec!["busiest files", "changes"]).style(Style::new().bold()))
+        .block(Block::default().borders(Borders::TOP))
+        .render(layout[3], buf);
+    }
+}
+
+fn bar_chart<'a>(title: &'a str, values: &'a [(String, u64)], color: Color) -> BarChart<'a> {
+    BarChart::default()
+        .block(Block::default().title(title).borders(Borders::TOP))
+        .data(
+            &values
+                .iter()
+                .map(|(name, value)| (name.as_str(), *value))
+                .collect::<Vec<(&str, u64)>>(),
+        )
+        .bar_width(1)
+        .bar_gap(0)
+        .bar_style(Style::default().fg(color))
+        .value_style(Style::default().fg(Color::White))
+        .direction(Direction::Horizontal)
+}
+
+impl<'a> Widget for GroupColumns<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.block.inner(area);
+        self.block.render(area, buf);
+
+        if self.columns.is_empty() {
+            Paragraph::new(
+                "no ownership groups, set `ownerships` in the config file or pass \
+                 `--ownership NAME=PATTERN` or `--codeowners`",
+            )
+            .style(Style::default().fg(Color::White))
+            .wrap(Wrap { trim: true })
+            .render(inner, buf);
+            return;
+        }
+
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Ratio(1, self.columns.len() as u32);
+                self.columns.len()
+            ])
+            .split(inner);
+        for (column, area) in self.columns.into_iter().zip(layout.iter()) {
+            column.render(*area, buf);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::marker::PhantomData;
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction},
+    style::{Color, Modifier, Style, Stylize},
+    widgets::{BarChart, Block, Borders, Cell, Row, Table},
+};
+
+use crate::window::app::App;
+
+use super::Component;
+
+pub struct ScopesChart<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+/// Commits per scope and type, shaded by how busy the pair is.
+pub struct TypeScopeHeatmap<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+/// Shades from a quiet to a busy pair of the heatmap.
+const HEAT: [Color; 5] = [
+    Color::Blue,
+    Color::Cyan,
+    Color::Yellow,
+    Color::LightRed,
+    Color::Red,
+];
+
+impl<'a> Component<'a> for ScopesChart<'a> {
+    type Output = BarChart<'a>;
+    fn new(app: &'a App) -> Self::Output {
+        BarChart::default()
+            .block(
+                Block::default()
+                    .title("scopes")
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::Yellow)),
+            )
+            .data(
+                &app.scopes
+                    .iter()
+                    .map(|s| (s.0.as_str(), s.1))
+                    .collect::<Vec<(&str, u64)>>(),
+            )
+            .bar_width(2)
+            .bar_style(Style::default().fg(Color::Magenta))
+            .value_style(Style::default().fg(Color::White))
+            .direction(Direction::Horizontal)
+    }
+}
+
+impl<'a> Component<'a> for TypeScopeHeatmap<'a> {
+    type Output = Table<'a>;
+    fn new(app: &App) -> Self::Output {
+        let matrix = &app.type_scopes;
+        let max = matrix.max().max(1);
+
+        let rows: Vec<Row<'_>> = matrix
+            .scopes
+            .iter()
+            .zip(matrix.counts.iter())
+            .map(|(scope, counts)| {
+                let mut cells =
+                    vec![Cell::new(scope.to_string()).style(Style::default().fg(Color::Magenta))];
+                cells.extend(counts.iter().map(|count| match count {
+                    0 => Cell::new("·").style(Style::default().fg(Color::DarkGray)),
+                    _ => {
+                        let heat = ((count * HEAT.len() as u64 - 1) / max) as usize;
+                        Cell::new(count.to_string()).style(
+                            Style::default()
+                                .fg(Color::Black)
+                                .bg(HEAT[heat.min(HEAT.len() - 1)])
+                                .add_modifier(Modifier::BOLD),
+                        )
+                    }
+                }));
+                Row::new(cells)
+            })
+            .collect();
+
+        let mut header = vec!["scope \\ type".to_string()];
+        header.extend(matrix.types.iter().cloned());
+
+        let type_width = 80 / matrix.types.len().max(1) as u16;
+        let mut widths = vec![Constraint::Percentage(20)];
+        widths.extend(
+            matrix
+                .types
+                .iter()
+                .map(|_| Constraint::Percentage(type_width)),
+        );
+
+        Table::new(rows, widths)
+            .column_spacing(1)
+            .style(Style::new().fg(Color::Yellow))
+            .header(
+                Row::new(header)
+                    .style(Style::new().bold().fg(Color::Cyan))
+                    .bottom_margin(1),
+            )
+            .block(
+                Block::default()
+                    .fg(Color::Yellow)
+                    .borders(Borders::ALL)
+                    .title("types per scope")
+                    .title_alignment(Alignment::Center),
+            )
+    }
+}
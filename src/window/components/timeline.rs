@@ -0,0 +1,154 @@
+use std::marker::PhantomData;
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Widget},
+};
+
+use super::Component;
+use crate::window::{app::App, timeline::TimelineBucket};
+
+/// Commits per period of the timeline, stacked by type.
+pub struct TimelineCommits<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+/// Changed lines per period of the timeline, stacked by type.
+pub struct TimelineChurn<'a> {
+    marker: PhantomData<&'a ()>,
+}
+
+/// Colors of the stacked types, in the order of `Timeline.types`.
+const TYPE_COLORS: [Color; 7] = [
+    Color::Yellow,
+    Color::Cyan,
+    Color::Magenta,
+    Color::Green,
+    Color::LightBlue,
+    Color::LightRed,
+    Color::Gray,
+];
+
+/// Vertical bars of stacked values, the most recent ones when they do not all fit.
+pub struct StackedBars<'a> {
+    block: Block<'a>,
+    labels: Vec<String>,
+    values: Vec<Vec<u64>>,
+}
+
+impl<'a> Component<'a> for TimelineCommits<'a> {
+    type Output = StackedBars<'a>;
+    fn new(app: &App) -> Self::Output {
+        let period = app.timeline.period.name();
+        let title = format!("commits per {}, `b` day/week/month", period);
+        stacked_bars(app, title, |bucket| &bucket.commits)
+    }
+}
+
+impl<'a> Component<'a> for TimelineChurn<'a> {
+    type Output = StackedBars<'a>;
+    fn new(app: &App) -> Self::Output {
+        let title = format!("changed lines per {}", app.timeline.period.name());
+        stacked_bars(app, title, |bucket| &bucket.churn)
+    }
+}
+
+fn stacked_bars<'a>(
+    app: &App,
+    title: String,
+    values: fn(&TimelineBucket) -> &Vec<u64>,
+) -> StackedBars<'a> {
+    let (first, rest) = title.split_at(1);
+    let mut legend = vec![first.to_string().light_red(), rest.to_string().light_blue()];
+    for (indx, type_) in app.timeline.types.iter().enumerate() {
+        legend.push(Span::raw(" "));
+        legend.push(Span::styled(
+            format!("■ {}", type_),
+            Style::default().fg(TYPE_COLORS[indx % TYPE_COLORS.len()]),
+        ));
+    }
+
+    StackedBars {
+        block: Block::default()
+            .fg(Color::Yellow)
+            .borders(Borders::ALL)
+            .title(Line::from(legend))
+            .title_alignment(Alignment::Center),
+        labels: app
+            .timeline
+            .buckets
+            .iter()
+            .map(|bucket| bucket.label.clone())
+            .collect(),
+        values: app.timeline.buckets.iter().map(values).cloned().collect(),
+    }
+}
+
+impl<'a> Widget for StackedBars<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.block.inner(area);
+        self.block.render(area, buf);
+        if inner.height < 3 || inner.width < 2 || self.values.is_empty() {
+            return;
+        }
+
+        let slot = (inner.width as usize / self.values.len()).clamp(1, 8) as u16;
+        // bars are one column narrower than their slot once there is room for a gap
+        let bar_width = if slot > 1 { slot - 1 } else { slot };
+        let shown = (inner.width / slot) as usize;
+        let skipped = self.values.len().saturating_sub(shown);
+        let values = &self.values[skipped..];
+        let labels = &self.labels[skipped..];
+
+        let max = values
+            .iter()
+            .map(|stack| stack.iter().sum::<u64>())
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let height = (inner.height - 2) as u64;
+        let bottom = inner.bottom() - 2;
+
+        buf.set_string(
+            inner.x,
+            inner.y,
+            format!("max {}", max),
+            Style::default().fg(Color::White),
+        );
+
+        for (indx, stack) in values.iter().enumerate() {
+            let x = inner.x + indx as u16 * slot;
+            let mut total = 0;
+            for (type_indx, value) in stack.iter().enumerate() {
+                // rows are rounded on the running total so the segments add up to the bar
+                let from = (total * height + max / 2) / max;
+                total += value;
+                let to = (total * height + max / 2) / max;
+                for row in from..to {
+                    for dx in 0..bar_width {
+                        buf.get_mut(x + dx, bottom - row as u16)
+                            .set_symbol("█")
+                            .set_fg(TYPE_COLORS[type_indx % TYPE_COLORS.len()]);
+                    }
+                }
+            }
+        }
+
+        let axis = inner.bottom() - 1;
+        let style = Style::default().fg(Color::White);
+        if let Some(first) = labels.first() {
+            buf.set_string(inner.x, axis, first, style);
+        }
+        if let Some(last) = labels.last().filter(|_| labels.len() > 1) {
+            let x = (inner.x + (values.len() as u16 * slot))
+                .saturating_sub(last.len() as u16)
+                .max(inner.x + labels[0].len() as u16 + 1);
+            if x + last.len() as u16 <= inner.right() {
+                buf.set_string(x, axis, last, style);
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use std::marker::PhantomData;
+
+use ratatui::{
+    layout::Direction,
+    style::{Color, Style},
+    widgets::{BarChart, Block, Borders},
+};
+
+use crate::window::app::App;
+
+use super::Component;
+
+pub struct TrailersChart<'a> {
+    marker: PhantomData<&'a ()>,
+}
+impl<'a> Component<'a> for TrailersChart<'a> {
+    type Output = BarChart<'a>;
+    fn new(app: &'a App) -> Self::Output {
+        BarChart::default()
+            .block(
+                Block::default()
+                    .title("trailers")
+                    .borders(Borders::ALL)
+                    .style(Style::default().fg(Color::Yellow)),
+            )
+            .data(
+                &app.trailers
+                    .iter()
+                    .map(|t| (t.0.as_str(), t.1))
+                    .collect::<Vec<(&str, u64)>>(),
+            )
+            .bar_width(2)
+            .bar_style(Style::default().fg(Color::Cyan))
+            .value_style(Style::default().fg(Color::White))
+            .direction(Direction::Horizontal)
+    }
+}
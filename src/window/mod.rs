@@ -2,6 +2,7 @@ pub mod app;
 pub mod components;
 pub mod event;
 pub mod screen;
+pub mod timeline;
 pub mod tui;
 pub mod ui;
 pub mod update;
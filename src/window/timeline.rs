@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate};
+
+use crate::commit::CommitInfo;
+
+/// Types stacked on their own in the timeline, the rest is summed as `other`.
+const TIMELINE_TYPES: usize = 6;
+
+/// Length of a timeline bucket, cycled with `b`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Period {
+    Day,
+    #[default]
+    Week,
+    Month,
+}
+
+/// Commits and changed lines of one period, per type of `Timeline.types`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TimelineBucket {
+    pub label: String,
+    pub commits: Vec<u64>,
+    pub churn: Vec<u64>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Timeline {
+    pub period: Period,
+    pub types: Vec<String>,
+    /// Oldest first, periods without commits included.
+    pub buckets: Vec<TimelineBucket>,
+}
+
+impl Period {
+    pub fn next(self) -> Self {
+        match self {
+            Period::Day => Period::Week,
+            Period::Week => Period::Month,
+            Period::Month => Period::Day,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Period::Day => "day",
+            Period::Week => "week",
+            Period::Month => "month",
+        }
+    }
+
+    fn start(&self, day: NaiveDate) -> NaiveDate {
+        match self {
+            Period::Day => day,
+            Period::Week => day - Days::new(day.weekday().num_days_from_monday() as u64),
+            Period::Month => day.with_day(1).unwrap_or(day),
+        }
+    }
+
+    fn following(&self, start: NaiveDate) -> NaiveDate {
+        match self {
+            Period::Day => start + Days::new(1),
+            Period::Week => start + Days::new(7),
+            Period::Month => start + Months::new(1),
+        }
+    }
+
+    fn label(&self, start: NaiveDate) -> String {
+        match self {
+            Period::Day => start.format("%Y-%m-%d").to_string(),
+            Period::Week => start.format("%G-W%V").to_string(),
+            Period::Month => start.format("%Y-%m").to_string(),
+        }
+    }
+}
+
+impl Timeline {
+    pub fn collect(commits: &[CommitInfo], period: Period) -> Timeline {
+        let days = commits
+            .iter()
+            .filter_map(|commit| {
+                DateTime::from_timestamp(commit.time, 0).map(|time| (commit, time.date_naive()))
+            })
+            .collect::<Vec<_>>();
+
+        let mut types: Vec<(String, usize)> = vec![];
+        for (commit, _) in days.iter().filter(|(commit, _)| !commit.type_.is_empty()) {
+            match types.iter_mut().find(|(name, _)| *name == commit.type_) {
+                Some(entry) => entry.1 += 1,
+                None => types.push((commit.type_.clone(), 1)),
+            }
+        }
+        types.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        let mut types = types
+            .into_iter()
+            .take(TIMELINE_TYPES)
+            .map(|(name, _)| name)
+            .collect::<Vec<String>>();
+        let other = types.len();
+        if days
+            .iter()
+            .any(|(commit, _)| !types.contains(&commit.type_))
+        {
+            types.push("other".to_string());
+        }
+
+        let (Some(first), Some(last)) = (
+            days.iter().map(|(_, day)| *day).min(),
+            days.iter().map(|(_, day)| *day).max(),
+        ) else {
+            return Timeline {
+                period,
+                types,
+                buckets: vec![],
+            };
+        };
+
+        let mut starts = vec![period.start(first)];
+        while let Some(start) = starts.last().map(|start| period.following(*start)) {
+            if start > last {
+                break;
+            }
+            starts.push(start);
+        }
+
+        let positions = starts
+            .iter()
+            .enumerate()
+            .map(|(indx, start)| (*start, indx))
+            .collect::<HashMap<NaiveDate, usize>>();
+        let mut buckets = starts
+            .iter()
+            .map(|start| TimelineBucket {
+                label: period.label(*start),
+                commits: vec![0; types.len()],
+                churn: vec![0; types.len()],
+            })
+            .collect::<Vec<TimelineBucket>>();
+
+        for (commit, day) in days {
+            let Some(bucket) = positions
+                .get(&period.start(day))
+                .and_then(|indx| buckets.get_mut(*indx))
+            else {
+                continue;
+            };
+            let indx = types
+                .iter()
+                .take(other)
+                .position(|type_| *type_ == commit.type_)
+                .unwrap_or(other);
+            bucket.commits[indx] += 1;
+            bucket.churn[indx] += commit
+                .stats
+                .as_ref()
+                .map_or(0, |stats| stats.total_changes as u64);
+        }
+
+        Timeline {
+            period,
+            types,
+            buckets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commit::Stats;
+
+    #[test]
+    fn can_collect_timeline() {
+        let day = |day: &str| {
+            NaiveDate::parse_from_str(day, "%Y-%m-%d")
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap()
+                .and_utc()
+                .timestamp()
+        };
+        let commit = |type_: &str, time: i64, changes: usize| CommitInfo {
+            type_: type_.to_string(),
+            time,
+            stats: Some(Stats {
+                total_changes: changes,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let commits = [
+            commit("feat", day("2024-01-31"), 10),
+            commit("fix", day("2024-02-01"), 2),
+            commit("feat", day("2024-02-14"), 5),
+            commit("", day("2024-04-02"), 1),
+        ];
+
+        let months = Timeline::collect(&commits, Period::Month);
+        assert_eq!(months.types, vec!["feat", "fix", "other"]);
+        assert_eq!(
+            months
+                .buckets
+                .iter()
+                .map(|bucket| bucket.label.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["2024-01", "2024-02", "2024-03", "2024-04"]
+        );
+        assert_eq!(months.buckets[1].commits, vec![1, 1, 0]);
+        assert_eq!(months.buckets[1].churn, vec![5, 2, 0]);
+        assert_eq!(months.buckets[3].commits, vec![0, 0, 1]);
+
+        let weeks = Timeline::collect(&commits, Period::Week);
+        assert_eq!(weeks.buckets[0].label, "2024-W05");
+        assert_eq!(weeks.buckets[0].commits, vec![1, 1, 0]);
+        assert_eq!(weeks.buckets.len(), 10);
+
+        let days = Timeline::collect(&commits, Period::Day);
+        assert_eq!(days.buckets.len(), 63);
+        assert!(Timeline::collect(&[], Period::Day).buckets.is_empty());
+    }
+}
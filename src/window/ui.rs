@@ -1,29 +1,49 @@
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     prelude::Frame,
     style::{Color, Style, Stylize},
     text::Line,
-    widgets::{Block, Borders, TableState, Tabs},
+    widgets::{Block, Borders, Clear, Tabs},
 };
 
 use crate::window::components::Component;
 
 use crate::window::{
     app::App,
-    components::{chart::Chart, files::FilesTable, header::Header},
+    components::{
+        authors::AuthorsTable,
+        chart::Chart,
+        commits::{CommitFilesTable, CommitMessage, CommitsTable},
+        file_commits::{FileAuthorsTable, FileCommitsTable},
+        files::FilesTable,
+        filter_bar::FilterBar,
+        header::Header,
+        help::Help,
+        ownership::OwnershipGroups,
+        scopes::{ScopesChart, TypeScopeHeatmap},
+        timeline::{TimelineChurn, TimelineCommits},
+        trailers::TrailersChart,
+    },
+    update::KEYBINDINGS,
 };
 
+/// Tables are built from a shared borrow of the app and rendered with a copy of their state,
+/// written back for the scroll offset, so no frame copies the app.
 pub fn render_app(app: &mut App, f: &mut Frame) {
     let area = f.size();
 
+    let show_filter = app.filter_input.is_some() || !app.filter.is_empty();
+    let mut constraints = vec![Constraint::Length(3), Constraint::Length(5)];
+    if show_filter {
+        constraints.push(Constraint::Length(3));
+    }
+    constraints.push(Constraint::Min(10));
+
     let layout = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3),
-            Constraint::Min(4),
-            Constraint::Min(10),
-        ])
+        .constraints(constraints)
         .split(area);
+    let content = layout[layout.len() - 1];
 
     let block = Block::default().fg(Color::Black);
 
@@ -51,18 +71,93 @@ pub fn render_app(app: &mut App, f: &mut Frame) {
 
     f.render_widget(tabs, layout[0]);
     f.render_widget(Header::new(app), layout[1]);
+    if show_filter {
+        f.render_widget(FilterBar::new(app), layout[2]);
+    }
+
+    if app.active_title() == "Stats" {
+        let stats_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(8),
+                Constraint::Length(app.type_scopes.scopes.len().min(8) as u16 + 4),
+            ])
+            .split(content);
+        let charts = if app.trailers.is_empty() { 2 } else { 3 };
+        let charts_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, charts); charts as usize])
+            .split(stats_layout[0]);
 
-    match app.active_tab {
-        0 => f.render_widget(Chart::new(app), layout[2]),
-        _ => {}
+        f.render_widget(Chart::new(app), charts_layout[0]);
+        f.render_widget(ScopesChart::new(app), charts_layout[1]);
+        if !app.trailers.is_empty() {
+            f.render_widget(TrailersChart::new(app), charts_layout[2]);
+        }
+        f.render_widget(TypeScopeHeatmap::new(app), stats_layout[1]);
     };
 
-    match app.active_tab {
-        1 => f.render_stateful_widget(
-            FilesTable::new(&mut app.clone()),
-            layout[2],
-            &mut app.file_table_state,
-        ),
-        _ => {}
+    if app.active_title() == "Files" && app.file_drill_down.is_some() {
+        let files_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+            .split(content);
+        let mut state = app.file_commit_table_state.clone();
+        f.render_stateful_widget(FileCommitsTable::new(app), files_layout[0], &mut state);
+        app.file_commit_table_state = state;
+        f.render_widget(FileAuthorsTable::new(app), files_layout[1]);
+    } else if app.active_title() == "Files" {
+        let mut state = app.file_table_state.clone();
+        f.render_stateful_widget(FilesTable::new(app), content, &mut state);
+        app.file_table_state = state;
+    }
+
+    if app.active_title() == "Authors" {
+        let mut state = app.author_table_state.clone();
+        f.render_stateful_widget(AuthorsTable::new(app), content, &mut state);
+        app.author_table_state = state;
+    }
+
+    if app.active_title() == "Commits" {
+        let commits_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(content);
+        let detail_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(commits_layout[1]);
+
+        let mut state = app.commit_table_state.clone();
+        f.render_stateful_widget(CommitsTable::new(app), commits_layout[0], &mut state);
+        app.commit_table_state = state;
+        f.render_widget(CommitMessage::new(app), detail_layout[0]);
+        f.render_widget(CommitFilesTable::new(app), detail_layout[1]);
+    }
+
+    if app.active_title() == "Timeline" {
+        let timeline_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(content);
+        f.render_widget(TimelineCommits::new(app), timeline_layout[0]);
+        f.render_widget(TimelineChurn::new(app), timeline_layout[1]);
+    }
+
+    if app.active_title() == "Ownership" {
+        f.render_widget(OwnershipGroups::new(app), content);
+    }
+
+    if app.show_help {
+        let height = (KEYBINDINGS.len() as u16 + 2).min(area.height);
+        let width = 80.min(area.width);
+        let help_area = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height - height) / 2,
+            width,
+            height,
+        );
+        f.render_widget(Clear, help_area);
+        f.render_widget(Help::new(app), help_area);
     }
 }
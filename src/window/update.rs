@@ -1,17 +1,51 @@
 use crate::window::app::App;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
+/// Keys handled by `update`, listed by the `?` overlay.
+pub const KEYBINDINGS: [(&str, &str); 12] = [
+    ("h / l", "previous / next tab"),
+    ("S F A C T O", "jump to the tab starting with the letter"),
+    ("j / k, ↓ / ↑", "next / previous row or ownership group"),
+    ("Enter", "commits of the selected file"),
+    ("f", "toggle the sort order of the files or authors"),
+    ("s", "next sort column of the authors"),
+    ("b", "timeline by day, week or month"),
+    (
+        "/",
+        "filter commits, e.g., type:feat author:alice path:src/",
+    ),
+    ("Enter / Esc", "apply / cancel the filter being typed"),
+    ("?", "toggle this help"),
+    ("Esc", "close the file commits or the help, otherwise quit"),
+    ("q, Ctrl-C", "quit"),
+];
+
 pub fn update(app: &mut App, key_event: KeyEvent) {
+    if app.filter_input.is_some() {
+        return update_filter(app, key_event);
+    }
+    if app.show_help {
+        return update_help(app, key_event);
+    }
+
     match key_event.code {
-        KeyCode::Esc | KeyCode::Char('q') => app.quit(),
-        KeyCode::Char('c') | KeyCode::Char('C') => {
-            if key_event.modifiers == KeyModifiers::CONTROL {
+        KeyCode::Esc => {
+            if !app.close_file_drill_down() {
                 app.quit()
             }
         }
+        KeyCode::Char('q') => app.quit(),
+        KeyCode::Enter => app.open_file_drill_down(),
+        KeyCode::Char('c') | KeyCode::Char('C') if key_event.modifiers == KeyModifiers::CONTROL => {
+            app.quit()
+        }
+        KeyCode::Char('/') => app.open_filter(),
+        KeyCode::Char('?') => app.toggle_help(),
         KeyCode::Char('h') => app.prev_tab(),
         KeyCode::Char('l') => app.next_tab(),
-        KeyCode::Char('f') => app.toggle_sort_files(),
+        KeyCode::Char('f') => app.toggle_sort_order(),
+        KeyCode::Char('s') => app.next_sort_authors(),
+        KeyCode::Char('b') => app.next_timeline_period(),
         KeyCode::Char('j') | KeyCode::Down => app.table_next_item(),
         KeyCode::Char('k') | KeyCode::Up => app.table_prev_item(),
         _ => {
@@ -24,3 +58,38 @@ pub fn update(app: &mut App, key_event: KeyEvent) {
         }
     };
 }
+
+/// Keys of the open filter prompt, typed characters go to the expression.
+fn update_filter(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        KeyCode::Char('c') | KeyCode::Char('C') if key_event.modifiers == KeyModifiers::CONTROL => {
+            app.quit()
+        }
+        KeyCode::Esc => app.close_filter(),
+        KeyCode::Enter => app.submit_filter(),
+        KeyCode::Backspace => {
+            if let Some(input) = app.filter_input.as_mut() {
+                input.pop();
+            }
+            app.filter_error = None;
+        }
+        KeyCode::Char(c) => {
+            if let Some(input) = app.filter_input.as_mut() {
+                input.push(c);
+            }
+            app.filter_error = None;
+        }
+        _ => {}
+    }
+}
+
+/// Keys of the help overlay, anything but quitting closes it.
+fn update_help(app: &mut App, key_event: KeyEvent) {
+    match key_event.code {
+        KeyCode::Char('c') | KeyCode::Char('C') if key_event.modifiers == KeyModifiers::CONTROL => {
+            app.quit()
+        }
+        KeyCode::Char('q') => app.quit(),
+        _ => app.toggle_help(),
+    }
+}